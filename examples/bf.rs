@@ -9,7 +9,7 @@ fn main() {
     let mut pointer: WrapNum<usize> = wrap!(0, 29_999);
 
     let args: Vec<_> = env::args().collect();
-    if args.len() < 1 {
+    if args.len() < 2 {
         eprintln!("No user input passed");
         std::process::exit(1);
     }
@@ -9,13 +9,43 @@
 //! This library uses logic that does not change between debug and release modes, unlike some
 //! methods like [`std::intrinsics::wrapping_add()`]. As such, this library is not meant to be
 //! performance critical; it is simply meant to be a "one-and-done forget about it" variable.
+//!
+//! # Features
+//! The core [`WrapNum`] type only needs `core`, so this crate works in `#![no_std]` with
+//! `default-features = false`.
+//!
+//! * `alloc`: Indexing [`Vec`] with a [`WrapNum`], plus anything returning a [`Vec`] or
+//!   [`String`], such as [`deltas()`], [`distance_matrix()`], [`WrapNum::segments()`], and
+//!   [`WrapNum::clock_string()`].
+//! * `std` (default): Everything in `alloc`, indexing [`std::collections::HashMap`] with a
+//!   [`WrapNum`], and [`std::error::Error`] for the error types.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::ops::IndexMut;
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    ops::{
+        Add, AddAssign, BitAnd, BitOr, BitXor, Deref, Div, Index, Range, RangeInclusive, Rem, Shl,
+        Shr, Sub, SubAssign,
+    },
+    time::Duration,
+};
+#[cfg(feature = "std")]
 use std::{
-    fmt::Display,
-    ops::{Add, AddAssign, Index, IndexMut, Rem, Sub, SubAssign},
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
 };
 
-use num_traits::{zero, Bounded, One, ToPrimitive, Zero};
+use num_traits::{float::FloatCore, zero, Bounded, One, ToPrimitive, Zero};
 
 macro_rules! impl_from_wrapnum {
     ($($t:ty),*) => {
@@ -29,6 +59,49 @@ macro_rules! impl_from_wrapnum {
     };
 }
 
+macro_rules! impl_fmt_wrapnum {
+    ($($trait:ident),*) => {
+        $(
+            impl<T: core::fmt::$trait> core::fmt::$trait for WrapNum<T> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.value.fmt(f)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_bitwise_wrapnum {
+    ($($trait:ident::$method:ident),*) => {
+        $(
+            /// Applies the operation to [`WrapNum::value`] and then re-wraps the result, which
+            /// with a nonzero [`WrapNum::min`] can land somewhere unexpected: in `5..12`, `7 & 3`
+            /// is `3`, which wraps around to `10`.
+            impl<T> $trait<T> for WrapNum<T>
+            where
+                T: $trait<Output = T>
+                    + Add<Output = T>
+                    + Sub<Output = T>
+                    + Ord
+                    + Bounded
+                    + Rem<Output = T>
+                    + One
+                    + num_traits::NumCast
+                    + Copy,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: T) -> Self::Output {
+                    Self {
+                        value: self.wrapped(self.value.$method(rhs)),
+                        ..self
+                    }
+                }
+            }
+        )*
+    };
+}
+
 #[derive(Clone, Copy, Debug)]
 /// Number with arbitrary wrapping.
 pub struct WrapNum<T> {
@@ -38,17 +111,153 @@ pub struct WrapNum<T> {
     pub min: T,
     /// Maximimum value.
     pub max: T,
+    /// Whether [`WrapNum::max`] is itself a valid value. See [`WrapNum::is_inclusive()`].
+    ///
+    /// This is private so it can only be set by the inclusive constructors, which means a
+    /// [`WrapNum`] can no longer be built with a struct literal. Use the constructors or [`wrap!`]
+    /// instead.
+    inclusive: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Errors from the fallible [`WrapNum`] operations.
+pub enum WrapNumError {
+    /// A signed delta was bigger in magnitude than allowed.
+    DeltaTooLarge {
+        /// Delta that was passed in.
+        delta: i64,
+        /// Largest magnitude that was allowed.
+        limit: u64,
+    },
+    /// A string could not be parsed as a number.
+    Parse,
+    /// [`WrapNum::max`] was not greater than [`WrapNum::min`], so no value fits.
+    EmptyRange,
+}
+
+impl Display for WrapNumError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DeltaTooLarge { delta, limit } => {
+                write!(
+                    f,
+                    "delta `{delta}` exceeds the allowed magnitude of `{limit}`"
+                )
+            }
+            Self::Parse => write!(f, "could not parse number"),
+            Self::EmptyRange => write!(f, "`max` is not greater than `min`"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WrapNumError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A value did not fit the bounds of a [`WrapNum`].
+pub enum OutOfRange {
+    /// Value was less than [`WrapNum::min`].
+    BelowMin,
+    /// Value was greater than allowed by [`WrapNum::max`].
+    AboveMax,
+    /// [`WrapNum::max`] was not greater than [`WrapNum::min`], so no value fits.
+    EmptyRange,
+}
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BelowMin => write!(f, "value is less than `min`"),
+            Self::AboveMax => write!(f, "value is greater than `max`"),
+            Self::EmptyRange => write!(f, "`max` is not greater than `min`"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Reasons [`WrapNumBuilder::build()`] can fail.
+pub enum BuildError {
+    /// The bounds were empty or `value` was outside `[min, max)`.
+    OutOfRange(OutOfRange),
+}
+
+impl From<OutOfRange> for BuildError {
+    fn from(err: OutOfRange) -> Self {
+        Self::OutOfRange(err)
+    }
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfRange(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+#[derive(Clone, Copy, Debug)]
+/// Builder for [`WrapNum`], made with [`WrapNum::builder()`].
+pub struct WrapNumBuilder<T> {
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+}
+
+impl<T> WrapNumBuilder<T>
+where
+    T: Bounded + Zero + Ord + Copy,
+{
+    /// Set [`WrapNum::min`], which defaults to [`zero()`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set [`WrapNum::max`], which defaults to [`num_traits::Bounded::max_value()`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Validate the bounds and build the [`WrapNum`].
+    pub fn build(self) -> Result<WrapNum<T>, BuildError> {
+        let min = self.min.unwrap_or_else(zero);
+        let max = self.max.unwrap_or_else(T::max_value);
+
+        if min >= max {
+            Err(OutOfRange::EmptyRange.into())
+        } else if self.value < min {
+            Err(OutOfRange::BelowMin.into())
+        } else if self.value >= max {
+            Err(OutOfRange::AboveMax.into())
+        } else {
+            Ok(WrapNum {
+                value: self.value,
+                min,
+                max,
+                inclusive: false,
+            })
+        }
+    }
 }
 
 impl<T> Display for WrapNum<T>
 where
-    T: std::fmt::Display,
+    T: core::fmt::Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
     }
 }
 
+impl_fmt_wrapnum!(LowerHex, UpperHex, Binary, Octal);
+
 impl<T> PartialEq for WrapNum<T>
 where
     T: Copy + PartialEq,
@@ -67,6 +276,31 @@ where
     }
 }
 
+/// Read-only access to [`WrapNum::value`]. There is deliberately no `DerefMut`, since writing
+/// through it would skip the wrapping.
+impl<T> Deref for WrapNum<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for WrapNum<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Borrows only [`WrapNum::value`], not the bounds, matching [`PartialEq`], which also only
+/// compares the value. This lets a [`WrapNum`] be passed where a generic `B: Borrow<T>` is taken.
+impl<T> Borrow<T> for WrapNum<T> {
+    fn borrow(&self) -> &T {
+        &self.value
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl<T, U> Index<WrapNum<U>> for Vec<T>
 where
     U: ToPrimitive + Copy,
@@ -74,236 +308,1938 @@ where
     type Output = T;
 
     fn index(&self, index: WrapNum<U>) -> &Self::Output {
-        let idx = index
-            .value
-            .to_usize()
-            .expect("Failed to convert index to usize");
-        &self[idx]
+        &self[index.as_index()]
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T, U> IndexMut<WrapNum<U>> for Vec<T>
 where
     U: ToPrimitive + Copy,
 {
     fn index_mut(&mut self, index: WrapNum<U>) -> &mut Self::Output {
-        &mut self[index
-            .value
-            .to_usize()
-            .expect("Failed to convert index to usize")]
+        &mut self[index.as_index()]
     }
 }
 
-impl<T> WrapNum<T>
+/// Indexes bytes rather than chars, like indexing [`str::as_bytes()`], so non-ASCII text gives
+/// the individual UTF-8 bytes.
+impl<U> Index<WrapNum<U>> for str
 where
-    T: Add<Output = T> + Sub<Output = T> + Ord + Bounded + Rem<Output = T> + Copy,
+    U: ToPrimitive + Copy,
 {
-    fn wrapped_result(value: T, min: T, max: T) -> T {
-        let range = max - min;
-        (value - min) % range + min
+    type Output = u8;
+
+    fn index(&self, index: WrapNum<U>) -> &Self::Output {
+        &self.as_bytes()[index.as_index()]
     }
 }
 
-impl<T> Add for WrapNum<T>
+/// Indexes bytes rather than chars, the same as indexing a [`str`] with a [`WrapNum`].
+#[cfg(feature = "alloc")]
+impl<U> Index<WrapNum<U>> for String
 where
-    T: Add<Output = T> + Sub<Output = T> + Ord + Bounded + Rem<Output = T> + Copy,
+    U: ToPrimitive + Copy,
 {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        let wrapped_value = Self::wrapped_result(self.value + rhs.value, self.min, self.max);
+    type Output = u8;
 
-        Self {
-            value: wrapped_value,
-            min: self.min,
-            max: self.max,
-        }
+    fn index(&self, index: WrapNum<U>) -> &Self::Output {
+        &self.as_str()[index]
     }
 }
 
-impl<T> Add<T> for WrapNum<T>
+#[cfg(feature = "std")]
+impl<K, V, U, S> Index<WrapNum<U>> for HashMap<K, V, S>
 where
-    T: Add<Output = T> + Sub<Output = T> + Ord + Bounded + Rem<Output = T> + Copy,
+    K: Eq + Hash + Borrow<U>,
+    U: Eq + Hash,
+    S: BuildHasher,
 {
-    type Output = Self;
+    type Output = V;
 
-    fn add(self, rhs: T) -> Self::Output {
-        let wrapped_value = Self::wrapped_result(self.value + rhs, self.min, self.max);
-
-        Self {
-            value: wrapped_value,
-            min: self.min,
-            max: self.max,
-        }
+    fn index(&self, index: WrapNum<U>) -> &Self::Output {
+        &self[&index.value]
     }
 }
 
-impl<T> Sub for WrapNum<T>
+impl<T> WrapNum<T>
 where
-    T: Sub<Output = T> + Add<Output = T> + Rem<Output = T> + Ord + Bounded + One + Copy,
+    T: num_traits::NumCast + PartialOrd + Copy,
 {
-    type Output = Self;
+    /// Position of `n` as a `u128`, where the offset between any two values of `T` fits.
+    fn to_bits(n: T) -> u128 {
+        n.to_i128().map_or_else(
+            || n.to_u128().expect("Failed to convert to u128"),
+            |n| n as u128,
+        )
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        let result = if self.value < rhs.value {
-            self.max - self.min + (self.value - rhs.value)
+    /// Value of `T` at a position from [`WrapNum::to_bits()`].
+    fn from_bits(bits: u128) -> T {
+        T::from(bits as i128)
+            .or_else(|| T::from(bits))
+            .expect("Wrapped value is always within the range")
+    }
+
+    /// Split `n` into whether it is negative and its magnitude.
+    fn to_magnitude(n: T) -> (bool, u128) {
+        match n.to_i128() {
+            Some(n) => (n < 0, n.unsigned_abs()),
+            None => (false, Self::to_bits(n)),
+        }
+    }
+
+    /// Wrap `value` into the `count` values from `min` up, where [`None`] means all of `u128`.
+    fn wrapped_bits(value: T, min: T, count: Option<u128>) -> T {
+        let Some(count) = count else {
+            return value;
+        };
+        let offset = if value >= min {
+            Self::to_bits(value).wrapping_sub(Self::to_bits(min)) % count
         } else {
-            self.value - rhs.value
+            match Self::to_bits(min).wrapping_sub(Self::to_bits(value)) % count {
+                0 => 0,
+                back => count - back,
+            }
         };
+        Self::from_bits(Self::to_bits(min).wrapping_add(offset))
+    }
 
-        let wrapped_value = Self::wrapped_result(result, self.min, self.max);
+    /// Wrap `value` into `[min, max)`, whichever side of the range it is on.
+    fn wrapped_result(value: T, min: T, max: T) -> T {
+        // Work with offsets from `min` as `u128` rather than `value - min`, which can overflow
+        // `T` when they're far apart.
+        let count = Self::to_bits(max).wrapping_sub(Self::to_bits(min));
+        Self::wrapped_bits(value, min, Some(count))
+    }
 
-        Self {
-            value: wrapped_value,
-            min: self.min,
-            max: self.max,
+    /// Number of values in the range, or [`None`] for an inclusive range over all of a 128-bit
+    /// `T`.
+    fn count(&self) -> Option<u128> {
+        let span = Self::to_bits(self.max).wrapping_sub(Self::to_bits(self.min));
+        if self.inclusive {
+            span.checked_add(1)
+        } else {
+            Some(span)
         }
     }
+
+    /// Wrap `value` into the bounds of `self`, counting [`WrapNum::max`] as a valid value when
+    /// [`WrapNum::is_inclusive()`] is set.
+    fn wrapped(&self, value: T) -> T {
+        Self::wrapped_bits(value, self.min, self.count())
+    }
+
+    /// Move [`WrapNum::value`] `by` places up the ring, or down if not `up`.
+    fn step(&mut self, up: bool, by: u128) {
+        let offset = Self::to_bits(self.value).wrapping_sub(Self::to_bits(self.min));
+        let offset = match self.count() {
+            // Every `u128` offset is in range, so plain wrapping is exact.
+            None if up => offset.wrapping_add(by),
+            None => offset.wrapping_sub(by),
+            Some(count) => {
+                let by = by % count;
+                if up {
+                    if by >= count - offset {
+                        by - (count - offset)
+                    } else {
+                        offset + by
+                    }
+                } else if by > offset {
+                    count - (by - offset)
+                } else {
+                    offset - by
+                }
+            }
+        };
+        self.value = Self::from_bits(Self::to_bits(self.min).wrapping_add(offset));
+    }
 }
 
-impl<T> Sub<T> for WrapNum<T>
+impl<T> WrapNum<T>
 where
-    T: Sub<Output = T> + Add<Output = T> + Rem<Output = T> + Ord + Bounded + One + Copy,
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + One
+        + num_traits::NumCast
+        + Copy,
 {
-    type Output = Self;
+    /// Wrap a bare `value` into `[min, max)` without making a [`WrapNum`]. The result is always
+    /// at least `min` and less than `max`, whichever side of the range `value` starts on.
+    ///
+    /// # Panics
+    /// This will panic if `max <= min`.
+    pub fn wrap(value: T, min: T, max: T) -> T {
+        assert!(max > min, "`max` must be greater than `min`.");
+        Self::wrapped_result(value, min, max)
+    }
 
-    fn sub(self, rhs: T) -> Self::Output {
-        let result = if self.value < rhs {
-            self.max - self.min + (self.value - rhs)
-        } else {
-            self.value - rhs
-        };
+    /// Lenient [`WrapNum::new_min_max()`] for bounds that may come in either order. The smaller
+    /// of `a` and `b` is used as [`WrapNum::min`], the larger as [`WrapNum::max`], and `value` is
+    /// wrapped in between them.
+    ///
+    /// # Panics
+    /// This will panic if `a == b`.
+    pub fn new_min_max_normalized(value: T, a: T, b: T) -> Self {
+        assert!(a != b, "`max` must be greater than `min`.");
+        let (min, max) = if a < b { (a, b) } else { (b, a) };
+        Self {
+            value: Self::wrapped_result(value, min, max),
+            min,
+            max,
+            inclusive: false,
+        }
+    }
+
+    /// The value `n` steps ahead, without changing `self`. Large `n` is reduced before adding,
+    /// so multiple laps can't overflow `T`.
+    pub fn nth_after(&self, n: T) -> T {
+        (*self + n).value
+    }
+
+    /// Apply `op` to the values of `self` and `other`, and wrap the result into a new range
+    /// `[out_min, out_max)`. Neither input's bounds carry over, so the two can differ freely.
+    ///
+    /// # Panics
+    /// This will panic if `out_max <= out_min`.
+    pub fn combine<F: Fn(T, T) -> T>(self, other: Self, op: F, out_min: T, out_max: T) -> Self {
+        assert!(out_max > out_min, "`max` must be greater than `min`.");
+        Self {
+            value: Self::wrapped_result(op(self.value, other.value), out_min, out_max),
+            min: out_min,
+            max: out_max,
+            inclusive: false,
+        }
+    }
 
-        let wrapped_value = Self::wrapped_result(result, self.min, self.max);
+    /// Add every delta in `deltas`, in order, wrapping after each one.
+    pub fn apply_deltas(self, deltas: &[T]) -> Self {
+        deltas.iter().fold(self, |acc, &delta| acc + delta)
+    }
+
+    /// Apply `f` to [`WrapNum::value`] and wrap the result back into range.
+    pub fn map<F: FnOnce(T) -> T>(self, f: F) -> Self {
+        Self {
+            value: self.wrapped(f(self.value)),
+            ..self
+        }
+    }
 
+    /// A copy of `self` with the same bounds, at `v` wrapped into range.
+    pub fn with_value(&self, v: T) -> Self {
         Self {
-            value: wrapped_value,
-            min: self.min,
-            max: self.max,
+            value: self.wrapped(v),
+            ..*self
+        }
+    }
+
+    /// New [`WrapNum`] with the bounds of `template`, at `value` wrapped into range.
+    pub fn like(template: &Self, value: T) -> Self {
+        template.with_value(value)
+    }
+
+    /// Set [`WrapNum::value`], wrapping `v` into range.
+    pub fn set(&mut self, v: T) {
+        self.value = self.wrapped(v);
+        self.assert_valid();
+    }
+
+    /// Set [`WrapNum::value`], erroring instead of wrapping if `v` is out of range.
+    pub fn try_set(&mut self, v: T) -> Result<(), OutOfRange> {
+        if v < self.min {
+            Err(OutOfRange::BelowMin)
+        } else if !self.contains(v) {
+            Err(OutOfRange::AboveMax)
+        } else {
+            self.value = v;
+            Ok(())
         }
     }
 }
 
-impl<T> AddAssign<T> for WrapNum<T>
+impl<T> WrapNum<T>
 where
-    T: Add<Output = T> + Sub<Output = T> + Ord + Bounded + Rem<Output = T> + Copy,
+    T: num_traits::Num + Ord + Bounded + num_traits::NumCast + Copy,
 {
-    fn add_assign(&mut self, rhs: T) {
-        let result = self.value + rhs;
-
-        self.value = Self::wrapped_result(result, self.min, self.max);
+    /// Parse `s` in the given `radix` and wrap it into `[min, max)`, erroring if that range is
+    /// empty.
+    ///
+    /// # Panics
+    /// This will panic if `radix` is not in the range from 2 to 36, like the standard library
+    /// parsers do.
+    pub fn from_str_radix(s: &str, radix: u32, min: T, max: T) -> Result<Self, WrapNumError> {
+        if max <= min {
+            return Err(WrapNumError::EmptyRange);
+        }
+        let value = T::from_str_radix(s, radix).map_err(|_| WrapNumError::Parse)?;
+        Ok(Self {
+            value: Self::wrapped_result(value, min, max),
+            min,
+            max,
+            inclusive: false,
+        })
     }
 }
 
-impl<T> SubAssign<T> for WrapNum<T>
+impl<T> WrapNum<T>
 where
-    T: Sub<Output = T> + Add<Output = T> + Rem<Output = T> + Ord + Bounded + One + Copy,
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + Ord
+        + One
+        + Zero
+        + Copy,
 {
-    fn sub_assign(&mut self, rhs: T) {
-        let result = if self.value < rhs {
-            self.max - self.min + (self.value - rhs)
+    /// Add `rhs`, reflecting off the boundaries instead of wrapping around.
+    ///
+    /// The value bounces between [`WrapNum::min`] and the last value before wrapping (`max - 1`,
+    /// or `max` itself if [`WrapNum::is_inclusive()`] is set), so overshooting the top by `n`
+    /// lands `n` steps below it. The direction is not stored: [`WrapNum::value`] is always treated
+    /// as being on the way up from `min`, so to drive an oscillator, keep the origin around and
+    /// add the total step count to it. A negative `rhs` heads down toward `min` first instead.
+    pub fn reflecting_add(self, rhs: T) -> Self {
+        let top = self.last() - self.min;
+        if top == zero() {
+            return Self {
+                value: self.min,
+                ..self
+            };
+        }
+
+        // Every `top` steps is one pass between the ends, so only the parity of the passes and
+        // the leftover steps matter. Working in passes keeps everything within `[0, top]`.
+        let (passes, steps) = (rhs / top, rhs % top);
+        let going_up = passes % (T::one() + T::one()) == zero();
+        // Going down is going up from the mirrored offset. Only `steps` is negated, since
+        // `passes` may be `T::min_value()` and its parity is all that is needed.
+        let negative = rhs < zero();
+        let (offset, steps) = if negative {
+            (top - (self.value - self.min), zero::<T>() - steps)
+        } else {
+            (self.value - self.min, steps)
+        };
+        let offset = if going_up {
+            if steps > top - offset {
+                top - (steps - (top - offset))
+            } else {
+                offset + steps
+            }
         } else {
-            self.value - rhs
+            let offset = top - offset;
+            if steps > offset {
+                steps - offset
+            } else {
+                offset - steps
+            }
         };
+        let offset = if negative { top - offset } else { offset };
 
-        self.value = Self::wrapped_result(result, self.min, self.max);
+        Self {
+            value: self.min + offset,
+            ..self
+        }
     }
 }
 
-impl<T> From<T> for WrapNum<T>
+impl<T> WrapNum<T>
 where
-    T: Copy + Bounded + Zero,
+    T: Add<Output = T> + Sub<Output = T> + num_traits::SaturatingAdd + Ord + Zero + Copy,
 {
-    fn from(value: T) -> Self {
-        Self {
-            value,
-            min: zero(),
-            max: T::max_value(),
-        }
+    /// Slide the whole window, moving [`WrapNum::value`], [`WrapNum::min`], and [`WrapNum::max`]
+    /// by `by` so the width stays the same.
+    ///
+    /// The shift saturates, so a window pushed past the limits of `T` stops at them.
+    pub fn shift_bounds(&mut self, by: T) {
+        self.assert_valid();
+        // Saturate whichever end leads the shift, then move everything by what it really moved.
+        let by = if by >= zero() {
+            self.max.saturating_add(&by) - self.max
+        } else {
+            self.min.saturating_add(&by) - self.min
+        };
+
+        self.value = self.value + by;
+        self.min = self.min + by;
+        self.max = self.max + by;
+        self.assert_valid();
     }
 }
 
-//  The reason why we can't just make one generic implementation is because I believe we need a
-//  real type on the righthandside of the "for".
-impl_from_wrapnum!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl<T: FloatCore> WrapNum<T> {
+    /// Float counterpart to the wrapping done by the operators, which need [`Ord`]. The
+    /// remainder is taken euclidean-style, so inputs below `min` wrap around to the top.
+    fn float_wrapped_result(value: T, min: T, max: T) -> T {
+        let range = max - min;
+        let offset = (value - min) % range;
+        let offset = if offset < T::zero() {
+            offset + range
+        } else {
+            offset
+        };
+        // Adding `range` to a tiny negative offset can round up to exactly `range`.
+        if offset >= range {
+            min
+        } else {
+            offset + min
+        }
+    }
 
-impl<T> Default for WrapNum<T>
-where
-    T: Bounded + Zero,
-{
-    /// The default behavior is to set [`WrapNum::value`] and [`WrapNum::min`] to [`zero()`] and
-    /// [`WrapNum::max`] to [`num::Bounded::max_value()`].
-    fn default() -> Self {
-        WrapNum {
-            value: zero(),
-            min: zero(),
-            max: T::max_value(),
+    /// Add `rhs` to a floating point [`WrapNum`], such as a phase or angle.
+    pub fn add_float(self, rhs: T) -> Self {
+        Self {
+            value: Self::float_wrapped_result(self.value + rhs, self.min, self.max),
+            ..self
+        }
+    }
+
+    /// Subtract `rhs` from a floating point [`WrapNum`], wrapping around to the top when going
+    /// below [`WrapNum::min`].
+    pub fn sub_float(self, rhs: T) -> Self {
+        Self {
+            value: Self::float_wrapped_result(self.value - rhs, self.min, self.max),
+            ..self
         }
     }
 }
 
 impl<T> WrapNum<T>
 where
-    T: Bounded + Zero + PartialOrd,
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + One
+        + num_traits::NumCast
+        + Copy,
 {
-    /// Create new wrapped number and automatic zeroed [`WrapNum::value`].
-    pub fn new(max: T) -> Self {
-        Self {
-            max,
-            ..Default::default()
-        }
+    /// Where adding `rhs` would land, without changing `self`. The same as
+    /// [`WrapNum::nth_after()`].
+    pub fn peek_add(&self, rhs: T) -> T {
+        (*self + rhs).value
     }
 
-    /// Create new wrapped number with given max.
+    /// Where subtracting `rhs` would land, without changing `self`.
+    pub fn peek_sub(&self, rhs: T) -> T {
+        (*self - rhs).value
+    }
+
+    /// Increment by one in place and return the new [`WrapNum::value`].
+    pub fn tick(&mut self) -> T {
+        *self += T::one();
+        self.value
+    }
+
+    /// Decrement by one in place and return the new [`WrapNum::value`].
+    pub fn tick_back(&mut self) -> T {
+        self.assert_valid();
+        self.value = if self.value == self.min {
+            self.last()
+        } else {
+            self.value - T::one()
+        };
+        self.assert_valid();
+        self.value
+    }
+
+    /// Set [`WrapNum::value`], clamping `v` to [`WrapNum::min`] or the last value before wrapping
+    /// instead of wrapping it.
+    pub fn clamped_set(&mut self, v: T) {
+        self.value = v.clamp(self.min, self.last());
+        self.assert_valid();
+    }
+
+    /// Lenient [`WrapNum::new_min_max()`] that clamps `value` to `min` or the last value before
+    /// `max` instead of panicking, like [`WrapNum::clamped_set()`].
     ///
     /// # Panics
-    /// This will panic if `value > max`.
-    pub fn new_max(value: T, max: T) -> Self {
-        assert!(!(value > max), "`value` is greater than `max`.");
+    /// This will panic if `max <= min`.
+    pub fn saturating_new_min_max(value: T, min: T, max: T) -> Self {
+        assert!(max > min, "`max` must be greater than `min`.");
         Self {
-            value,
+            value: value.clamp(min, max - T::one()),
+            min,
             max,
-            ..Default::default()
+            inclusive: false,
         }
     }
 
-    /// Create new wrapped number with given min/max.
+    /// Advance by one, skipping over `forbidden` if that is where it would land.
     ///
-    /// # Panics
-    /// This will panic if `value > max` or `value < min`.
-    pub fn new_min_max(value: T, min: T, max: T) -> Self {
-        if value > max {
-            panic!("`value` is greater than `max`.");
-        } else if value < min {
-            panic!("`value` is less than `min`.");
+    /// If `forbidden` is the only value in the range, this stays put.
+    pub fn next_skipping(self, forbidden: T) -> Self {
+        let next = self + T::one();
+        if next.value != forbidden {
+            return next;
+        }
+        let next = next + T::one();
+        if next.value == forbidden {
+            self
+        } else {
+            next
         }
-        Self { value, min, max }
     }
 }
 
-impl<T: PartialEq> WrapNum<T> {
-    pub fn total_eq(self, other: &Self) -> bool {
-        self.value == other.value && self.min == other.min && self.max == other.max
+impl<T> WrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + Zero
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    /// Move [`WrapNum::value`] one step closer to zero, doing nothing if it is already zero.
+    ///
+    /// The step wraps like any other, so in a range that does not contain zero it goes around
+    /// instead of stopping.
+    pub fn toward_zero(&mut self) {
+        match self.value.cmp(&zero()) {
+            Ordering::Less => *self += T::one(),
+            Ordering::Greater => *self -= T::one(),
+            Ordering::Equal => {}
+        }
     }
 }
 
-#[macro_export]
-/// Create [`WrapNum`] with value, minimum and maximum.
-///
-/// # Running
-/// 1. With one value passed, which will set that as the maximum and default everything else to
-///    `0`.
-/// 2. With one inclusive value passed (`=5`) and defaults everything else as above.
-/// 3. With a value and a maximum.
-/// 4. With a value, a minimum, and a maximum.
-/// 5. With a range passed (`5..30`).
-/// 6. With an inclusive range passed (`5..=30`).
+impl<T: Zero + Bounded + PartialEq + Debug> WrapNum<T> {
+    /// Whether this is [`WrapNum::zero()`], which works with any bounds as the additive identity.
+    fn is_identity(&self) -> bool {
+        self.value.is_zero() && self.min.is_zero() && self.max == T::max_value() && !self.inclusive
+    }
+
+    /// Catch arithmetic between numbers with different bounds in debug builds, since only the
+    /// bounds of the left operand are kept.
+    fn debug_assert_same_bounds(&self, other: &Self) {
+        let dots = |inclusive| if inclusive { "..=" } else { ".." };
+        let same =
+            self.min == other.min && self.max == other.max && self.inclusive == other.inclusive;
+        debug_assert!(
+            same || self.is_identity() || other.is_identity(),
+            "Mismatched bounds: left is `{:?}{}{:?}` but right is `{:?}{}{:?}`.",
+            self.min,
+            dots(self.inclusive),
+            self.max,
+            other.min,
+            dots(other.inclusive),
+            other.max
+        );
+    }
+}
+
+/// Bounds come from the left operand, and must match the right operand in debug builds.
+impl<T> Add for WrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + Zero
+        + Debug
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.debug_assert_same_bounds(&rhs);
+        self + rhs.value
+    }
+}
+
+impl<T> Add<T> for WrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: T) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Bounds come from the left operand, and must match the right operand in debug builds. In
+/// release builds only [`WrapNum::value`] of the right operand is used.
+impl<T> Sub for WrapNum<T>
+where
+    T: Sub<Output = T>
+        + Add<Output = T>
+        + Rem<Output = T>
+        + Ord
+        + Bounded
+        + Zero
+        + One
+        + Debug
+        + num_traits::NumCast
+        + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.debug_assert_same_bounds(&rhs);
+        self - rhs.value
+    }
+}
+
+impl<T> Sub<T> for WrapNum<T>
+where
+    T: Sub<Output = T>
+        + Add<Output = T>
+        + Rem<Output = T>
+        + Ord
+        + Bounded
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: T) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T> AddAssign<T> for WrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    fn add_assign(&mut self, rhs: T) {
+        // `step` relies on starting from a valid offset.
+        self.assert_valid();
+        let (negative, by) = Self::to_magnitude(rhs);
+        self.step(!negative, by);
+        self.assert_valid();
+    }
+}
+
+impl<T> SubAssign<T> for WrapNum<T>
+where
+    T: Sub<Output = T>
+        + Add<Output = T>
+        + Rem<Output = T>
+        + Ord
+        + Bounded
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    fn sub_assign(&mut self, rhs: T) {
+        self.assert_valid();
+        let (negative, by) = Self::to_magnitude(rhs);
+        self.step(negative, by);
+        self.assert_valid();
+    }
+}
+
+/// Adds [`WrapNum::value`] of `rhs`, keeping the bounds of the left operand, which must match the
+/// right operand in debug builds.
+impl<T> AddAssign for WrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + Zero
+        + Debug
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.debug_assert_same_bounds(&rhs);
+        *self += rhs.value;
+    }
+}
+
+/// Subtracts [`WrapNum::value`] of `rhs`, keeping the bounds of the left operand, which must
+/// match the right operand in debug builds.
+impl<T> SubAssign for WrapNum<T>
+where
+    T: Sub<Output = T>
+        + Add<Output = T>
+        + Rem<Output = T>
+        + Ord
+        + Bounded
+        + Zero
+        + Debug
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.debug_assert_same_bounds(&rhs);
+        *self -= rhs.value;
+    }
+}
+
+impl<T> From<T> for WrapNum<T>
+where
+    T: Copy + Bounded + Zero,
+{
+    fn from(value: T) -> Self {
+        Self {
+            value,
+            min: zero(),
+            max: T::max_value(),
+            inclusive: false,
+        }
+    }
+}
+
+//  The reason why we can't just make one generic implementation is because I believe we need a
+//  real type on the righthandside of the "for".
+impl_from_wrapnum!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl_bitwise_wrapnum!(BitAnd::bitand, BitOr::bitor, BitXor::bitxor);
+
+/// Shifts [`WrapNum::value`] and then re-wraps the result. Bits shifted past the top of `T` are
+/// dropped, and shifting by at least the bit width of `T` shifts everything out, leaving zero to
+/// be wrapped instead of panicking.
+impl<T> Shl<u32> for WrapNum<T>
+where
+    T: num_traits::CheckedShl
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + Zero
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        let shifted = self.value.checked_shl(rhs).unwrap_or_else(zero);
+        Self {
+            value: self.wrapped(shifted),
+            ..self
+        }
+    }
+}
+
+/// Shifts [`WrapNum::value`] and then re-wraps the result. Shifting by at least the bit width of
+/// `T` shifts everything out, leaving zero to be wrapped instead of panicking.
+impl<T> Shr<u32> for WrapNum<T>
+where
+    T: num_traits::CheckedShr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + Zero
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        let shifted = self.value.checked_shr(rhs).unwrap_or_else(zero);
+        Self {
+            value: self.wrapped(shifted),
+            ..self
+        }
+    }
+}
+
+/// Non-panicking counterpart to [`WrapNum::new_min_max()`], taking `(value, min, max)` and
+/// requiring `value` to be within `[min, max)`.
+impl<T: PartialOrd> TryFrom<(T, T, T)> for WrapNum<T> {
+    type Error = OutOfRange;
+
+    fn try_from((value, min, max): (T, T, T)) -> Result<Self, Self::Error> {
+        if max <= min {
+            Err(OutOfRange::EmptyRange)
+        } else if value < min {
+            Err(OutOfRange::BelowMin)
+        } else if value >= max {
+            Err(OutOfRange::AboveMax)
+        } else {
+            Ok(Self {
+                value,
+                min,
+                max,
+                inclusive: false,
+            })
+        }
+    }
+}
+
+/// Non-panicking counterpart to [`WrapNum::new_min_max_inclusive()`], taking
+/// `(value, min..=max)` and requiring `value` to be within `[min, max]`.
+impl<T> TryFrom<(T, RangeInclusive<T>)> for WrapNum<T>
+where
+    T: Bounded + Zero + One + num_traits::CheckedAdd + PartialOrd + Copy,
+{
+    type Error = OutOfRange;
+
+    fn try_from((value, range): (T, RangeInclusive<T>)) -> Result<Self, Self::Error> {
+        let (min, max) = range.into_inner();
+        if max < min {
+            Err(OutOfRange::EmptyRange)
+        } else if value < min {
+            Err(OutOfRange::BelowMin)
+        } else if value > max {
+            Err(OutOfRange::AboveMax)
+        } else {
+            Ok(Self::new_min_max_inclusive(value, min, max))
+        }
+    }
+}
+
+/// Yields every value in the range exactly once, in order starting at [`WrapNum::min`], and then
+/// stops, no matter where [`WrapNum::value`] is. For an endlessly cycling iterator, use
+/// [`StepWrapNum`] instead.
+impl<T> IntoIterator for WrapNum<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + One + PartialEq + Copy,
+    RangeInclusive<T>: Iterator<Item = T>,
+{
+    type Item = T;
+    type IntoIter = RangeInclusive<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.min..=self.last()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> WrapNum<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + One + PartialEq + Copy,
+    RangeInclusive<T>: Iterator<Item = T>,
+{
+    /// Every value in the range, in order. This is the eager version of the [`IntoIterator`]
+    /// implementation, and allocates space for the whole width of the range at once.
+    pub fn range_values(&self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+}
+
+/// Lossy conversion of [`WrapNum::value`], since [`f64`] cannot exactly hold every 64-bit (or
+/// wider) integer.
+impl<T: ToPrimitive> From<WrapNum<T>> for f64 {
+    fn from(wrap_num: WrapNum<T>) -> Self {
+        wrap_num
+            .value
+            .to_f64()
+            .expect("Failed to convert value to f64")
+    }
+}
+
+impl<T> Default for WrapNum<T>
+where
+    T: Bounded + Zero,
+{
+    /// The default behavior is to set [`WrapNum::value`] and [`WrapNum::min`] to [`zero()`] and
+    /// [`WrapNum::max`] to [`num::Bounded::max_value()`].
+    fn default() -> Self {
+        WrapNum {
+            value: zero(),
+            min: zero(),
+            max: T::max_value(),
+            inclusive: false,
+        }
+    }
+}
+
+/// Uses the same bounds as [`WrapNum::default()`], `[zero(), T::max_value())`. Since
+/// [`WrapNum::max`] is never reached, [`Bounded::max_value()`] sits one below it, at the last value
+/// before wrapping.
+impl<T> Bounded for WrapNum<T>
+where
+    T: Bounded + Zero + One + Sub<Output = T>,
+{
+    fn min_value() -> Self {
+        Self::default()
+    }
+
+    fn max_value() -> Self {
+        Self {
+            value: T::max_value() - T::one(),
+            ..Default::default()
+        }
+    }
+}
+
+/// [`Zero::zero()`] uses the same bounds as [`WrapNum::default()`], and [`Zero::is_zero()`] checks
+/// whether [`WrapNum::value`] is zero. It is the identity for [`Add`] with any bounds, so it is
+/// exempt from the matching-bounds check.
+impl<T> Zero for WrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + Zero
+        + Debug
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: Bounded + Zero + PartialOrd,
+{
+    /// Create new wrapped number and automatic zeroed [`WrapNum::value`].
+    ///
+    /// # Panics
+    /// This will panic if `max` is not greater than zero.
+    pub fn new(max: T) -> Self {
+        assert!(max > zero(), "`max` must be greater than `min`.");
+        Self {
+            max,
+            ..Default::default()
+        }
+    }
+
+    /// Create new wrapped number with given max.
+    ///
+    /// # Panics
+    /// This will panic if `value >= max` or `max` is zero.
+    pub fn new_max(value: T, max: T) -> Self {
+        assert!(max > zero(), "`max` must be greater than `min`.");
+        assert!(value < max, "`value` must be less than `max`.");
+        Self {
+            value,
+            max,
+            ..Default::default()
+        }
+    }
+
+    /// Create new wrapped number with given min/max.
+    ///
+    /// # Panics
+    /// This will panic if `value >= max`, `value < min`, or `max <= min`.
+    pub fn new_min_max(value: T, min: T, max: T) -> Self {
+        if max <= min {
+            panic!("`max` must be greater than `min`.");
+        } else if value >= max {
+            panic!("`value` must be less than `max`.");
+        } else if value < min {
+            panic!("`value` is less than `min`.");
+        }
+        Self {
+            value,
+            min,
+            max,
+            inclusive: false,
+        }
+    }
+
+    /// Non-panicking [`WrapNum::new_max()`], returning [`None`] where that would panic.
+    pub fn checked_new_max(value: T, max: T) -> Option<Self> {
+        (max > zero() && value < max).then(|| Self {
+            value,
+            max,
+            ..Default::default()
+        })
+    }
+
+    /// Non-panicking [`WrapNum::new_min_max()`], returning [`None`] where that would panic.
+    pub fn checked_new_min_max(value: T, min: T, max: T) -> Option<Self> {
+        (max > min && value < max && value >= min).then_some(Self {
+            value,
+            min,
+            max,
+            inclusive: false,
+        })
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: Bounded + Zero + One + num_traits::CheckedAdd + PartialOrd + Copy,
+{
+    /// Create new wrapped number between `min` and `max`, starting at `min`.
+    ///
+    /// # Panics
+    /// This will panic if `max <= min`.
+    pub fn new_range(min: T, max: T) -> Self {
+        Self::new_min_max(min, min, max)
+    }
+
+    /// Create new wrapped number covering `range`, starting at [`Range::start`].
+    ///
+    /// # Panics
+    /// This will panic if `range` is empty.
+    pub fn from_range(range: Range<T>) -> Self {
+        Self::new_min_max(range.start, range.start, range.end)
+    }
+
+    /// Create new wrapped number covering `range`, starting at [`RangeInclusive::start()`]. An
+    /// `end` of `T::max_value()` is kept as an inclusive [`WrapNum::max`].
+    ///
+    /// # Panics
+    /// This will panic if `range` is empty.
+    pub fn from_range_inclusive(range: RangeInclusive<T>) -> Self {
+        let (min, max) = range.into_inner();
+        Self::new_min_max_inclusive(min, min, max)
+    }
+
+    /// Create new wrapped number with an inclusive max and automatic zeroed [`WrapNum::value`].
+    ///
+    /// # Panics
+    /// This will panic if `max` is less than zero.
+    pub fn new_inclusive(max: T) -> Self {
+        Self::new_min_max_inclusive(zero(), zero(), max)
+    }
+
+    /// Create new wrapped number with given min/max, where `max` is itself a valid value.
+    ///
+    /// This is stored as an exclusive `max + 1` when that fits in `T`, and otherwise as `max`
+    /// with [`WrapNum::is_inclusive()`] set, so nothing overflows at `T::max_value()`.
+    ///
+    /// # Panics
+    /// This will panic if `value > max`, `value < min`, or `max < min`.
+    pub fn new_min_max_inclusive(value: T, min: T, max: T) -> Self {
+        if max < min {
+            panic!("`max` must not be less than `min`.");
+        } else if value > max {
+            panic!("`value` is greater than `max`.");
+        } else if value < min {
+            panic!("`value` is less than `min`.");
+        }
+        match max.checked_add(&T::one()) {
+            Some(max) => Self {
+                value,
+                min,
+                max,
+                inclusive: false,
+            },
+            None => Self {
+                value,
+                min,
+                max,
+                inclusive: true,
+            },
+        }
+    }
+
+    /// Rebuild from `(value, min..=last)`, as given by [`WrapNum::into_parts_inclusive()`].
+    pub fn from_parts_inclusive(parts: (T, RangeInclusive<T>)) -> Result<Self, OutOfRange> {
+        Self::try_from(parts)
+    }
+
+    /// [`WrapNum::reset()`] with an inclusive `max`, storing it like
+    /// [`WrapNum::new_min_max_inclusive()`] does.
+    pub fn reset_inclusive(&mut self, value: T, min: T, max: T) -> Result<(), OutOfRange> {
+        *self = Self::try_from((value, min..=max))?;
+        Ok(())
+    }
+}
+
+impl<T> WrapNum<T> {
+    /// Whether [`WrapNum::max`] is itself a valid value, making the range `[min, max]` rather
+    /// than `[min, max)`. The inclusive constructors only set this when `max + 1` would overflow
+    /// `T`, such as for `wrap!(=255u8)`, and otherwise store `max + 1` with this unset.
+    pub fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    /// Take out [`WrapNum::value`], for any `T`.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Split into `(value, min, max)`.
+    pub fn into_parts(self) -> (T, T, T) {
+        (self.value, self.min, self.max)
+    }
+
+    /// Start building a wrapped number at `value`, validating the bounds once
+    /// [`WrapNumBuilder::build()`] is called.
+    pub fn builder(value: T) -> WrapNumBuilder<T> {
+        WrapNumBuilder {
+            value,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl<T: PartialEq> WrapNum<T> {
+    /// Compare every field, including the bounds, unlike `==`, which only compares the value.
+    pub fn total_eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.min == other.min
+            && self.max == other.max
+            && self.inclusive == other.inclusive
+    }
+}
+
+impl<T: Ord> WrapNum<T> {
+    /// Order by [`WrapNum::min`], then [`WrapNum::max`], then [`WrapNum::is_inclusive()`], then
+    /// [`WrapNum::value`]. This is a total order over every field, for sorting configurations, and
+    /// it agrees with [`WrapNum::total_eq()`].
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        (&self.min, &self.max, self.inclusive, &self.value).cmp(&(
+            &other.min,
+            &other.max,
+            other.inclusive,
+            &other.value,
+        ))
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: ToPrimitive + Copy,
+{
+    /// [`WrapNum::value`] as a [`usize`], for passing around as an index.
+    ///
+    /// # Panics
+    /// This will panic if [`WrapNum::value`] does not fit in a [`usize`], such as when it is
+    /// negative. Use [`WrapNum::try_as_index()`] to handle that instead.
+    pub fn as_index(&self) -> usize {
+        self.try_as_index()
+            .expect("Failed to convert index to usize")
+    }
+
+    /// [`WrapNum::value`] as a [`usize`], or [`None`] if it does not fit.
+    pub fn try_as_index(&self) -> Option<usize> {
+        self.value.to_usize()
+    }
+
+    /// Convert [`WrapNum::value`], [`WrapNum::min`], and [`WrapNum::max`] into another numeric type.
+    ///
+    /// Returns [`None`] if any of the three does not fit in `U`. An inclusive max is stored as an
+    /// exclusive `max + 1` when that fits in `U`, the same as the inclusive constructors.
+    pub fn cast<U>(self) -> Option<WrapNum<U>>
+    where
+        U: num_traits::NumCast + num_traits::CheckedAdd + One,
+    {
+        let max = U::from(self.max)?;
+        let (max, inclusive) = match self.inclusive.then(|| max.checked_add(&U::one())) {
+            Some(Some(next)) => (next, false),
+            Some(None) => (max, true),
+            None => (max, false),
+        };
+        Some(WrapNum {
+            value: U::from(self.value)?,
+            min: U::from(self.min)?,
+            max,
+            inclusive,
+        })
+    }
+}
+
+impl<T: PartialOrd> WrapNum<T> {
+    /// Rebuild from `(value, min, max)`, the same as [`WrapNum::try_from()`].
+    pub fn from_parts(parts: (T, T, T)) -> Result<Self, OutOfRange> {
+        Self::try_from(parts)
+    }
+
+    /// Replace [`WrapNum::value`], [`WrapNum::min`], and [`WrapNum::max`] together, leaving
+    /// `self` untouched if the new parts aren't valid.
+    pub fn reset(&mut self, value: T, min: T, max: T) -> Result<(), OutOfRange> {
+        *self = Self::try_from((value, min, max))?;
+        Ok(())
+    }
+
+    /// Whether `v` is within `[min, max)`, or `[min, max]` if [`WrapNum::is_inclusive()`] is set.
+    pub fn contains(&self, v: T) -> bool {
+        self.min <= v && (v < self.max || self.inclusive && v == self.max)
+    }
+
+    /// Whether `v` is within `[min, max]`, comparing against the stored [`WrapNum::max`].
+    ///
+    /// The inclusive constructors and [`wrap!`] forms store the end they are given as an
+    /// exclusive `max + 1`, so `wrap!(7, (5)..=(30))` gives `true` for `31` here. Use
+    /// [`WrapNum::contains()`] to check against the values the range actually takes.
+    pub fn contains_inclusive(&self, v: T) -> bool {
+        self.min <= v && v <= self.max
+    }
+
+    /// Whether the range has no values at all, which the constructors never allow.
+    pub fn is_empty(&self) -> bool {
+        !(self.min < self.max || self.inclusive && self.min == self.max)
+    }
+
+    /// Whether the bounds are non-empty and [`WrapNum::value`] is within them.
+    fn is_valid(&self) -> bool {
+        let below_max = self.value < self.max || self.inclusive && self.value == self.max;
+        !self.is_empty() && self.min <= self.value && below_max
+    }
+
+    /// Catch a [`WrapNum`] left invalid, such as by a direct field write, before and after it is
+    /// changed in debug builds. This does nothing in release builds.
+    fn assert_valid(&self) {
+        debug_assert!(
+            self.is_valid(),
+            "Invalid state: `value` is outside of `min` and `max`."
+        );
+    }
+
+    /// [`WrapNum::value`] if this is in a valid state, or `fallback` if something like a direct
+    /// field write left it out of range.
+    pub fn value_or(self, fallback: T) -> T {
+        if self.is_valid() {
+            self.value
+        } else {
+            fallback
+        }
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: num_traits::CheckedMul + One + PartialOrd + Copy,
+{
+    /// Raise [`WrapNum::value`] to `exp`, returning [`None`] instead of wrapping if the true
+    /// result falls outside `[min, max)` (or overflows `T`).
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        let value = num_traits::checked_pow(self.value, exp as usize)?;
+        self.contains(value).then_some(Self { value, ..self })
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: num_traits::CheckedAdd + Sub<Output = T> + One + PartialEq + Copy,
+{
+    /// Half-open range of values this can take, `min..max`.
+    ///
+    /// # Panics
+    /// This will panic if [`WrapNum::is_inclusive()`] is set, like for `wrap!(=255u8)`, since no
+    /// [`Range`] of `T` ends past `max`. Use [`WrapNum::range_inclusive()`] instead.
+    pub fn range(&self) -> Range<T> {
+        assert!(
+            !self.inclusive,
+            "`max` is inclusive, so there is no `Range` for it."
+        );
+        self.min..self.max
+    }
+
+    /// Every value this can take, `min..=max - 1`, or `min..=max` if [`WrapNum::is_inclusive()`]
+    /// is set.
+    pub fn range_inclusive(&self) -> RangeInclusive<T> {
+        self.min..=self.last()
+    }
+
+    /// Number of distinct values in the range, as `T`.
+    ///
+    /// # Panics
+    /// This will panic if that does not fit in `T`, like the 256 values of `wrap!(=255u8)`.
+    pub fn width(&self) -> T {
+        (self.last() - self.min)
+            .checked_add(&T::one())
+            .expect("Width does not fit in `T`")
+    }
+
+    /// Number of distinct values in the range, which is also how many the [`IntoIterator`]
+    /// implementation yields. The same as [`WrapNum::width()`].
+    ///
+    /// # Panics
+    /// This will panic if that does not fit in `T`, like the 256 values of `wrap!(=255u8)`.
+    pub fn len(&self) -> T {
+        self.width()
+    }
+
+    /// Number of increments until [`WrapNum::value`] wraps back to [`WrapNum::min`].
+    ///
+    /// # Panics
+    /// This will panic if that does not fit in `T`, like the 256 steps from `0` in
+    /// `wrap!(=255u8)`.
+    pub fn remaining(&self) -> T {
+        (self.last() - self.value)
+            .checked_add(&T::one())
+            .expect("Remaining steps do not fit in `T`")
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + One + Ord + Copy,
+{
+    /// Distance from `a` going forward (incrementing) around the range until reaching `b`,
+    /// counting an inclusive [`WrapNum::max`] as a step.
+    fn forward_to(&self, a: T, b: T) -> T {
+        if b >= a {
+            b - a
+        } else {
+            // One lap is `last - min + 1`, which may not fit in `T`, so add the one last.
+            self.last() - self.min - (a - b) + T::one()
+        }
+    }
+
+    /// Whether [`WrapNum::value`] lies on the forward arc going from `a` up to `b`, including
+    /// both ends. The arc may cross the seam, so `on_arc(10, 2)` on a 12-position ring covers
+    /// `10, 11, 0, 1, 2`.
+    pub fn on_arc(self, a: T, b: T) -> bool {
+        self.forward_to(a, self.value) <= self.forward_to(a, b)
+    }
+
+    /// Compare [`WrapNum::value`] against `other` by how far each is forward from `pivot`, so
+    /// sorting with this goes around the ring starting at `pivot`.
+    pub fn compare_from(self, other: T, pivot: T) -> Ordering {
+        self.forward_to(pivot, self.value)
+            .cmp(&self.forward_to(pivot, other))
+    }
+
+    /// Split adding `n` into `(before, after)`: how many steps land before wrapping past the top,
+    /// and how many land after wrapping back to [`WrapNum::min`]. This maps onto the two copies
+    /// needed to write `n` items into a ring buffer starting at [`WrapNum::value`].
+    ///
+    /// `before` is saturated at the steps left until the top, so if `n` wraps more than once,
+    /// `after` holds everything past the first wrap and can be bigger than the width.
+    pub fn split_add(&self, n: T) -> (T, T) {
+        let room = self.last() - self.value;
+        let before = if n > room { room + T::one() } else { n };
+        (before, n - before)
+    }
+
+    /// Which way around the ring is shorter to reach `target`: [`Ordering::Greater`] to go forward
+    /// (increment), [`Ordering::Less`] to go backward (decrement), and [`Ordering::Equal`] when
+    /// already there or both ways are the same length.
+    pub fn direction_to(self, target: T) -> Ordering {
+        let forward = self.forward_to(self.value, target);
+        let backward = self.forward_to(target, self.value);
+        backward.cmp(&forward)
+    }
+
+    /// Length of the shorter arc between this and `other`, using the bounds of `self`. Values
+    /// exactly opposite each other are half the width apart either way.
+    pub fn wrapping_abs_diff(self, other: Self) -> T {
+        let (a, b) = (self.value, other.value);
+        self.forward_to(a, b).min(self.forward_to(b, a))
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + One + PartialEq + Copy,
+{
+    /// Whether [`WrapNum::value`] is sitting on [`WrapNum::min`].
+    pub fn is_at_min(&self) -> bool {
+        self.value == self.min
+    }
+
+    /// Whether [`WrapNum::value`] is the last value before wrapping, which is `max - 1` unless
+    /// [`WrapNum::is_inclusive()`] is set.
+    pub fn is_at_max(&self) -> bool {
+        self.value == self.last()
+    }
+
+    /// Last value before wrapping: [`WrapNum::max`] if it is inclusive, otherwise `max - 1`.
+    fn last(&self) -> T {
+        if self.inclusive {
+            self.max
+        } else {
+            self.max - T::one()
+        }
+    }
+
+    /// Split into `(value, min..=last)`, where `last` is the last value before wrapping. Unlike
+    /// [`WrapNum::into_parts()`], this keeps an inclusive [`WrapNum::max`].
+    pub fn into_parts_inclusive(self) -> (T, RangeInclusive<T>) {
+        (self.value, self.min..=self.last())
+    }
+
+    /// Reflect [`WrapNum::value`] across the ring, landing where going the same distance the other
+    /// way from [`WrapNum::min`] would. On a 12-position clock `3` becomes `9`, and `min` stays put.
+    pub fn mirror(self) -> Self {
+        let value = if self.value == self.min {
+            self.min
+        } else {
+            self.last() - (self.value - self.min - T::one())
+        };
+        Self { value, ..self }
+    }
+
+    /// Walk the ring backward forever, starting at [`WrapNum::value`] and going from
+    /// [`WrapNum::min`] back around to the last value. Use [`Iterator::take()`] to stop.
+    pub fn iter_rev(self) -> impl Iterator<Item = T> {
+        let (value, min, last) = (self.value, self.min, self.last());
+        core::iter::successors(Some(value), move |&v| {
+            Some(if v == min { last } else { v - T::one() })
+        })
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: Sub<Output = T> + ToPrimitive + PartialEq + Copy,
+{
+    /// Position of [`WrapNum::value`] within the range as a fraction in `[0, 1)`.
+    ///
+    /// An empty range (`min == max`) gives `0.0`.
+    pub fn progress(&self) -> f64 {
+        if self.max == self.min && !self.inclusive {
+            return 0.0;
+        }
+        let offset = (self.value - self.min)
+            .to_f64()
+            .expect("Failed to convert offset to f64");
+        // An inclusive `max` is one more value than the distance to it.
+        let width = (self.max - self.min)
+            .to_f64()
+            .expect("Failed to convert width to f64")
+            + f64::from(u8::from(self.inclusive));
+        offset / width
+    }
+
+    /// Index of the nearest of `steps` evenly spaced detents around the ring, where detent `0`
+    /// sits on [`WrapNum::min`] and the last detent neighbors the first.
+    ///
+    /// # Panics
+    /// This will panic if `steps` is `0`.
+    pub fn detent(self, steps: usize) -> usize {
+        assert!(steps > 0, "`steps` must be greater than `0`.");
+        FloatCore::round(self.progress() * steps as f64) as usize % steps
+    }
+}
+
+impl<T> WrapNum<T>
+where
+    T: ToPrimitive + num_traits::NumCast + PartialOrd + Copy,
+{
+    /// Add a signed delta, erroring if its magnitude is bigger than the width of the range (more
+    /// than one full lap), which usually means the delta is garbage.
+    ///
+    /// Use [`WrapNum::checked_add_signed_within()`] to pick a different limit.
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`].
+    pub fn checked_add_signed(self, delta: i64) -> Result<Self, WrapNumError> {
+        let (_, min, max) = self.to_i128_parts();
+        let width = u64::try_from((max - min).unsigned_abs()).unwrap_or(u64::MAX);
+        self.checked_add_signed_within(delta, width)
+    }
+
+    /// Add a signed delta, erroring if its magnitude is bigger than `limit`.
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`].
+    pub fn checked_add_signed_within(self, delta: i64, limit: u64) -> Result<Self, WrapNumError> {
+        if delta.unsigned_abs() > limit {
+            return Err(WrapNumError::DeltaTooLarge { delta, limit });
+        }
+        Ok(self.wrapping_add_signed(delta))
+    }
+
+    /// Add a signed delta of any width up to [`i64`], going backward for negative deltas. Deltas
+    /// bigger than the range wrap as many times as needed.
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`].
+    pub fn wrapping_add_signed<D: Into<i64>>(self, delta: D) -> Self {
+        let (value, min, max) = self.to_i128_parts();
+        let wrapped = (value - min + i128::from(delta.into())).rem_euclid(max - min) + min;
+
+        Self {
+            value: T::from(wrapped).expect("Wrapped value is always within the range"),
+            ..self
+        }
+    }
+
+    /// Signed number of steps along the shorter arc to `target`: positive to go forward
+    /// (increment), negative to go backward (decrement). When `target` is exactly opposite, both
+    /// ways are the same length and this goes forward.
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`], or the distance does not fit in an
+    /// [`i64`].
+    pub fn signed_distance_to(self, target: T) -> i64 {
+        let (value, min, max) = self.to_i128_parts();
+        let target = target.to_i128().expect("Failed to convert to i128");
+        let width = max - min;
+        let forward = (target - value).rem_euclid(width);
+        let shortest = if forward * 2 <= width {
+            forward
+        } else {
+            forward - width
+        };
+        i64::try_from(shortest).expect("Failed to convert to i64")
+    }
+
+    /// Multiply [`WrapNum::value`] by `rhs` and wrap the product into range. The multiply is
+    /// done in an [`i128`], so products too big for `T` still wrap correctly; [`None`] is only
+    /// returned if the product (or `T` itself) doesn't fit in an [`i128`].
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        let min = self.min.to_i128()?;
+        let max = self.max.to_i128()? + i128::from(self.inclusive);
+        let product = self.value.to_i128()?.checked_mul(rhs.to_i128()?)?;
+        let wrapped = (product - min).rem_euclid(max - min) + min;
+
+        Some(Self {
+            value: T::from(wrapped).expect("Wrapped value is always within the range"),
+            ..self
+        })
+    }
+
+    /// Subtract a signed delta without wrapping, returning [`None`] if the result would go below
+    /// [`WrapNum::min`] (or, for a negative delta, reach [`WrapNum::max`]).
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`].
+    pub fn checked_sub_signed(self, delta: i64) -> Option<Self> {
+        let (value, min, max) = self.to_i128_parts();
+        let result = value - i128::from(delta);
+        (min..max).contains(&result).then(|| Self {
+            value: T::from(result).expect("Result is always within the range"),
+            ..self
+        })
+    }
+
+    /// Add `rhs` in place and return how many times that wrapped: positive for rolling over the
+    /// top, negative for rolling under [`WrapNum::min`] (with a negative `rhs`).
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`].
+    pub fn add_tracking(&mut self, rhs: T) -> i64 {
+        self.assert_valid();
+        let (value, min, max) = self.to_i128_parts();
+        let rhs = rhs.to_i128().expect("Failed to convert to i128");
+        let offset = value - min + rhs;
+
+        self.value = T::from(offset.rem_euclid(max - min) + min)
+            .expect("Wrapped value is always within the range");
+        self.assert_valid();
+        i64::try_from(offset.div_euclid(max - min)).expect("Wrap count does not fit in i64")
+    }
+
+    /// Shift the range to start at zero, so `[min, max)` becomes `[0, max - min)` with
+    /// [`WrapNum::value`] keeping its position relative to [`WrapNum::min`].
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`] or the width does not fit in a [`u64`].
+    pub fn to_unsigned_range(self) -> WrapNum<u64> {
+        let (value, min, max) = self.to_i128_parts();
+        let narrow = |n: i128| u64::try_from(n).expect("Failed to convert to u64");
+        WrapNum {
+            value: narrow(value - min),
+            min: 0,
+            max: narrow(max - min),
+            inclusive: false,
+        }
+    }
+
+    /// Split `[min, max)` into `n` arcs as `(start, end)` pairs, with `end` exclusive. When the
+    /// width does not divide evenly, the earliest arcs are one longer.
+    ///
+    /// If [`WrapNum::is_inclusive()`] is set, the last arc ends on the inclusive `max` instead.
+    ///
+    /// # Panics
+    /// This will panic if `T` does not fit in an [`i128`].
+    #[cfg(feature = "alloc")]
+    pub fn segments(self, n: usize) -> Vec<(T, T)> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let (_, min, max) = self.to_i128_parts();
+        let n = n as i128;
+        let (base, remainder) = ((max - min) / n, (max - min) % n);
+        // The last arc ends on `max` itself, which widens past `T` when it is inclusive.
+        let narrow = |v: i128| {
+            if v == max {
+                self.max
+            } else {
+                T::from(v).expect("Segment bounds are always within the range")
+            }
+        };
+
+        let mut start = min;
+        (0..n)
+            .map(|i| {
+                let end = start + base + i128::from(i < remainder);
+                let segment = (narrow(start), narrow(end));
+                start = end;
+                segment
+            })
+            .collect()
+    }
+
+    /// Move to new bounds, keeping the fractional position of [`WrapNum::value`] rather than its
+    /// absolute value. Positions that land between two values are truncated toward `new_min`.
+    ///
+    /// # Panics
+    /// This will panic if `new_max <= new_min`, or if `T` does not fit in an [`i128`].
+    pub fn rescale(&mut self, new_min: T, new_max: T) {
+        self.assert_valid();
+        let (value, min, max) = self.to_i128_parts();
+        let widen = |n: T| n.to_i128().expect("Failed to convert to i128");
+        let (new_min_wide, new_max_wide) = (widen(new_min), widen(new_max));
+        assert!(
+            new_max_wide > new_min_wide,
+            "`max` must be greater than `min`."
+        );
+
+        let offset = ((value - min) as u128)
+            .checked_mul((new_max_wide - new_min_wide) as u128)
+            .expect("Rescaled position does not fit in u128")
+            / (max - min) as u128;
+
+        self.value = T::from(new_min_wide + offset as i128)
+            .expect("Rescaled value is always within the new range");
+        self.min = new_min;
+        self.max = new_max;
+        self.inclusive = false;
+        self.assert_valid();
+    }
+
+    fn to_i128_parts(self) -> (i128, i128, i128) {
+        let widen = |n: T| n.to_i128().expect("Failed to convert to i128");
+        // Widened, an inclusive `max` can always be turned into an exclusive one.
+        let max = widen(self.max) + i128::from(self.inclusive);
+        (widen(self.value), widen(self.min), max)
+    }
+}
+
+impl WrapNum<u32> {
+    /// Interpret [`WrapNum::value`] as a number of seconds, such as a seconds-of-day counter.
+    pub fn as_duration(self) -> Duration {
+        Duration::from_secs(self.value.into())
+    }
+
+    /// Render a seconds-of-day counter as `HH:MM:SS`.
+    ///
+    /// Values past a day (from bounds other than `0..86400`) are folded back into one.
+    #[cfg(feature = "alloc")]
+    pub fn clock_string(self) -> String {
+        let seconds = self.value % 86_400;
+        format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// [`WrapNum`] that also counts how many times it has wrapped around.
+pub struct EpochWrapNum<T> {
+    /// Wrapped number.
+    pub num: WrapNum<T>,
+    /// Number of times [`EpochWrapNum::num`] has wrapped from the top back to its minimum.
+    pub epoch: u64,
+}
+
+impl<T> From<WrapNum<T>> for EpochWrapNum<T> {
+    fn from(num: WrapNum<T>) -> Self {
+        Self { num, epoch: 0 }
+    }
+}
+
+impl<T> EpochWrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + Zero
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    /// Rebuild an epoch-tracking number from its persisted parts.
+    ///
+    /// # Panics
+    /// This will panic if `value >= max` or `value < min`.
+    pub fn from_value_and_epoch(value: T, epoch: u64, min: T, max: T) -> Self {
+        Self {
+            num: WrapNum::new_min_max(value, min, max),
+            epoch,
+        }
+    }
+
+    /// Increment by one, bumping [`EpochWrapNum::epoch`] if that wrapped. The epoch saturates at
+    /// [`u64::MAX`] instead of overflowing.
+    pub fn increment(&mut self) {
+        self.num += T::one();
+        if self.num.value == self.num.min {
+            self.epoch = self.epoch.saturating_add(1);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// [`WrapNum`] that advances by a fixed step, usable as an endlessly cycling iterator.
+pub struct StepWrapNum<T> {
+    /// Wrapped number.
+    pub num: WrapNum<T>,
+    /// Amount added on every increment.
+    pub step: T,
+}
+
+impl<T> StepWrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    /// Create a stepping number starting at `num`.
+    pub fn new(num: WrapNum<T>, step: T) -> Self {
+        Self { num, step }
+    }
+
+    /// Advance by [`StepWrapNum::step`].
+    pub fn increment(&mut self) {
+        self.num += self.step;
+    }
+
+    /// Iterate one full lap, stopping as soon as the value comes back exactly to where it
+    /// started, rather than on the first step past it. Stepping always returns to the start
+    /// eventually, so this ends: a step that shares no factors with the width visits every value,
+    /// while one that divides the width only visits `width / step` of them.
+    pub fn cycle_once(self) -> impl Iterator<Item = T> {
+        let start = self.num.value;
+        let mut first = true;
+        self.take_while(move |&value| core::mem::replace(&mut first, false) || value != start)
+    }
+}
+
+impl<T> Iterator for StepWrapNum<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + One
+        + num_traits::NumCast
+        + Copy,
+{
+    type Item = T;
+
+    /// Yields the current value and then steps, forever.
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.num.value;
+        self.increment();
+        Some(value)
+    }
+}
+
+/// Shortest distance between `a` and `b` going either way around `[min, max)`.
+pub fn ring_distance<T>(a: T, b: T, min: T, max: T) -> T
+where
+    T: Sub<Output = T> + Ord + Copy,
+{
+    let direct = if a > b { a - b } else { b - a };
+    let around = (max - min) - direct;
+    direct.min(around)
+}
+
+/// Distance from `a` going forward (incrementing) around `[min, max)` until reaching `b`.
+#[cfg(feature = "alloc")]
+fn forward_distance<T>(a: T, b: T, min: T, max: T) -> T
+where
+    T: Sub<Output = T> + Ord + Copy,
+{
+    if b >= a {
+        b - a
+    } else {
+        (max - min) - (a - b)
+    }
+}
+
+/// Forward distance between each consecutive pair of `positions` on `[min, max)`.
+#[cfg(feature = "alloc")]
+pub fn deltas<T>(positions: &[T], min: T, max: T) -> Vec<T>
+where
+    T: Sub<Output = T> + Ord + Copy,
+{
+    positions
+        .windows(2)
+        .map(|pair| forward_distance(pair[0], pair[1], min, max))
+        .collect()
+}
+
+/// [`ring_distance()`] between every pair of `values`, so `matrix[i][j]` is the distance between
+/// `values[i]` and `values[j]`.
+#[cfg(feature = "alloc")]
+pub fn distance_matrix<T>(values: &[T], min: T, max: T) -> Vec<Vec<T>>
+where
+    T: Sub<Output = T> + Ord + Copy,
+{
+    values
+        .iter()
+        .map(|&a| {
+            values
+                .iter()
+                .map(|&b| ring_distance(a, b, min, max))
+                .collect()
+        })
+        .collect()
+}
+
+/// Value in `values` with the smallest total ring distance to every other value in `values`.
+///
+/// Unlike a circular mean this is not dragged around by outliers. Ties go to whichever value
+/// comes first in `values`.
+///
+/// # Panics
+/// This will panic if `values` is empty.
+pub fn circular_median<T>(values: &[T], min: T, max: T) -> T
+where
+    T: Sub<Output = T> + Ord + ToPrimitive + Copy,
+{
+    let total_distance = |candidate: T| -> u128 {
+        values
+            .iter()
+            .map(|&v| {
+                ring_distance(candidate, v, min, max)
+                    .to_u128()
+                    .expect("Failed to convert distance to u128")
+            })
+            .sum()
+    };
+
+    *values
+        .iter()
+        .min_by_key(|&&candidate| total_distance(candidate))
+        .expect("`values` is empty.")
+}
+
+/// Iterate `len` elements of `slice` starting at `start`, wrapping past the end of `slice` back to
+/// the beginning as many times as needed.
+pub fn view<T>(slice: &[T], start: WrapNum<usize>, len: usize) -> impl Iterator<Item = &T> {
+    let start = if slice.is_empty() {
+        0
+    } else {
+        start.value % slice.len()
+    };
+    slice.iter().cycle().skip(start).take(len)
+}
+
+/// Iterate every element of `slice` once, starting at `start` and wrapping around to end just
+/// before it.
+pub fn cycle_from<T>(slice: &[T], start: WrapNum<usize>) -> impl Iterator<Item = &T> {
+    view(slice, start, slice.len())
+}
+
+/// Apply `f` to every [`WrapNum::value`] in `slice`, wrapping each result back into the bounds of
+/// its own element.
+pub fn map_in_place<T, F>(slice: &mut [WrapNum<T>], mut f: F)
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Ord
+        + Bounded
+        + Rem<Output = T>
+        + One
+        + num_traits::NumCast
+        + Copy,
+    F: FnMut(T) -> T,
+{
+    for num in slice {
+        num.set(f(num.value));
+    }
+}
+
+/// Rotate `slice` left in place by `amount`, wrapped to the length of `slice`.
+pub fn rotate_by<T>(slice: &mut [T], amount: WrapNum<usize>) {
+    if !slice.is_empty() {
+        let len = slice.len();
+        slice.rotate_left(amount.value % len);
+    }
+}
+
+/// Up, down, left, and right neighbors of `(x, y)` on a torus, wrapping on both axes using the
+/// bounds of `x` and `y`.
+pub fn torus_neighbors(x: WrapNum<usize>, y: WrapNum<usize>) -> [(usize, usize); 4] {
+    [
+        (x.value, y.wrapping_add_signed(-1).value),
+        (x.value, y.wrapping_add_signed(1).value),
+        (x.wrapping_add_signed(-1).value, y.value),
+        (x.wrapping_add_signed(1).value, y.value),
+    ]
+}
+
+/// Reflect an out-of-range `raw` index back into `[0, len)`, mirroring at each end like
+/// `GL_MIRRORED_REPEAT` rather than jumping back to the start. For `len == 3`, indices `-3..6` map
+/// to `2, 1, 0, 0, 1, 2, 2, 1, 0`.
+///
+/// # Panics
+/// This will panic if `len` is `0`.
+pub fn mirror_index(raw: isize, len: usize) -> usize {
+    assert!(len > 0, "`len` must be greater than `0`.");
+    let period = 2 * len as isize;
+    let offset = raw.rem_euclid(period) as usize;
+    if offset < len {
+        offset
+    } else {
+        2 * len - 1 - offset
+    }
+}
+
+#[macro_export]
+/// Create [`WrapNum`] with value, minimum and maximum.
+///
+/// # Running
+/// 1. With one value passed, which will set that as the maximum and default everything else to
+///    `0`.
+/// 2. With one inclusive value passed (`=5`) and defaults everything else as above.
+/// 3. With a value and a maximum.
+/// 4. With a value, a minimum, and a maximum.
+/// 5. With a range passed (`5..30`).
+/// 6. With an inclusive range passed (`5..=30`).
+/// 7. With a value and a range (`7, (5)..(30)`).
+/// 8. With a value and an inclusive range (`7, (5)..=(30)`).
+///
+/// Any of the comma separated forms (1, 3, and 4) can be followed by `; step = n` to create a
+/// [`StepWrapNum`] that advances by `n`, such as `wrap!(0, 0, 12; step = 3)`.
 macro_rules! wrap {
     ($max:expr) => {
         $crate::WrapNum::new($max)
     };
     (=$max:expr) => {
-        $crate::WrapNum::new($max + 1)
+        $crate::WrapNum::new_inclusive($max)
+    };
+    ($v:expr, ($min:expr)..($max:expr)) => {
+        $crate::WrapNum::new_min_max($v, $min, $max)
+    };
+    ($v:expr, ($min:expr)..=($max:expr)) => {
+        $crate::WrapNum::new_min_max_inclusive($v, $min, $max)
     };
     ($v:expr, $max:expr) => {
         $crate::WrapNum::new_max($v, $max)
@@ -312,71 +2248,1413 @@ macro_rules! wrap {
         $crate::WrapNum::new_min_max($min, $min, $max)
     };
     (($min:expr)..=($max:expr)) => {
-        $crate::WrapNum::new_min_max($min, $min, $max + 1)
+        $crate::WrapNum::new_min_max_inclusive($min, $min, $max)
     };
     ($v:expr, $min:expr, $max:expr) => {
         $crate::WrapNum::new_min_max($v, $min, $max)
     };
+    ($first:expr $(, $rest:expr)*; step = $step:expr) => {
+        $crate::StepWrapNum::new($crate::wrap!($first $(, $rest)*), $step)
+    };
+}
+
+/// Exercises the `core`-only API without the `std`-gated tests. Tests still link `std`, so the
+/// real `no_std` check is `cargo build --no-default-features`.
+#[cfg(test)]
+mod compile_test {
+    use super::*;
+
+    #[test]
+    fn core_only_api() {
+        let mut dial: WrapNum<u8> = wrap!(7, 5, 12);
+        dial += 6;
+        dial -= 1;
+        assert!(dial.total_eq(&WrapNum::new_min_max(5, 5, 12)));
+        assert_eq!(dial.range(), 5..12);
+        assert_eq!(ring_distance(1, 11, 0, 12), 2);
+        assert_eq!(WrapNum::try_from((4, 5, 12)), Err(OutOfRange::BelowMin));
+        assert_eq!(view(&[1, 2, 3], wrap!(2, 0, 3), 2).sum::<i32>(), 4);
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_usize() {
+        let mut result = wrap!(50);
+        for _ in 0..60 {
+            result += 1;
+            println!("{}", result);
+        }
+    }
+
+    #[test]
+    fn months() {
+        let mut months = wrap!(11);
+        for _ in 0..11 {
+            months += 1;
+        }
+        assert_eq!(months.value, 0);
+    }
+
+    #[test]
+    fn custom_min() {
+        let mut mins = wrap!(5, 5, 7);
+        mins += 3;
+        // 5+1 = 6
+        // 6+1 = 7 (wrapped) = 5
+        // 5+1 = 6
+        assert_eq!(mins.value, 6);
+    }
+
+    #[test]
+    fn can_convert() {
+        let mut mins = wrap!(=5);
+        mins += 5_u16;
+    }
+
+    #[test]
+    fn has_indexing() {
+        let here = wrap!(5);
+        let oh = vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+        assert_eq!(oh[here], 10);
+    }
+
+    #[test]
+    fn are_equals() {
+        let mut here = wrap!(6);
+        here += 5;
+        println!("{}", here);
+        let there = wrap!(50);
+        assert_eq!(here, there + 5);
+    }
+
+    #[test]
+    fn into_integer() {
+        let here = wrap!(420, 0, 69420);
+        let hmm: WrapNum<u32> = 420.into();
+        let as_u32 = u32::from(here);
+        assert_eq!(hmm, 420);
+        assert_eq!(as_u32, 420);
+    }
+
+    #[test]
+    fn reflecting_negative() {
+        assert_eq!(wrap!(5i32, 0, 10).reflecting_add(-7), 2);
+        assert_eq!(wrap!(5i32, 0, 10).reflecting_add(-20), 3);
+        assert_eq!(wrap!(0i32, 0, 10).reflecting_add(-9), 9);
+        assert_eq!(wrap!(-3i8, -5, 5).reflecting_add(-2), -5);
+        assert_eq!(wrap!(3i8, -5, 5).reflecting_add(i8::MIN), 1);
+    }
+
+    #[test]
+    fn reflecting_wide_range() {
+        let origin = wrap!(0u8, 200);
+        assert_eq!(origin.reflecting_add(1), 1);
+        assert_eq!(origin.reflecting_add(250), 148);
+        assert_eq!(wrap!(190u8, 200).reflecting_add(255), 47);
+        assert_eq!(wrap!(0u8, 0, 255).reflecting_add(255), 253);
+    }
+
+    #[test]
+    fn reflecting_bounce() {
+        let origin = wrap!(0, 10);
+        let path: Vec<u32> = (0..=18).map(|n| origin.reflecting_add(n).value).collect();
+        assert_eq!(
+            path,
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(origin.reflecting_add(19), 1);
+        assert_eq!(wrap!(3, 2, 6).reflecting_add(3), 4);
+    }
+
+    #[test]
+    fn seconds_of_day_duration() {
+        let noon_ish = wrap!(45_296, 0, 86_400);
+        assert_eq!(
+            noon_ish.as_duration(),
+            Duration::from_secs(12 * 3600 + 34 * 60 + 56)
+        );
+    }
+
+    #[test]
+    fn cast_between_types() {
+        let index: WrapNum<usize> = wrap!(7, 2, 10);
+        let narrowed = index.cast::<u32>().unwrap();
+        assert!(narrowed.total_eq(&wrap!(7, 2, 10)));
+
+        let wide: WrapNum<u64> = wrap!(3, 0, 300);
+        assert!(wide.cast::<u8>().is_none());
+
+        let byte = wrap!(=255u8);
+        assert!(byte.cast::<u16>().unwrap().total_eq(&wrap!(=255u16)));
+        assert!(byte.cast::<u8>().unwrap().total_eq(&byte));
+    }
+
+    #[test]
+    fn median_on_ring() {
+        assert_eq!(circular_median(&[1, 2, 3], 0, 12), 2);
+        assert_eq!(circular_median(&[350, 358, 2, 5, 170], 0, 360), 2);
+    }
+
+    #[test]
+    fn boundary_flags() {
+        let mut counter = wrap!(2, 2, 5);
+        assert!(counter.is_at_min() && !counter.is_at_max());
+        assert_eq!(counter.remaining(), 3);
+        counter += 1;
+        assert!(!counter.is_at_min() && !counter.is_at_max());
+        assert_eq!(counter.remaining(), 2);
+        counter += 1;
+        assert!(!counter.is_at_min() && counter.is_at_max());
+        assert_eq!(counter.remaining(), 1);
+        counter += 1;
+        assert!(counter.is_at_min() && !counter.is_at_max());
+        assert_eq!(counter.remaining(), 3);
+    }
+
+    #[test]
+    fn view_wraps_slice() {
+        let items = ['a', 'b', 'c', 'd'];
+        let start = wrap!(2, 0, 4);
+        let seen: Vec<_> = view(&items, start, 5).copied().collect();
+        assert_eq!(seen, vec!['c', 'd', 'a', 'b', 'c']);
+        assert_eq!(view::<char>(&[], start, 3).count(), 0);
+    }
+
+    #[test]
+    fn progress_fraction() {
+        assert_eq!(wrap!(10, 10, 18).progress(), 0.0);
+        assert_eq!(wrap!(12, 10, 18).progress(), 0.25);
+        assert_eq!(wrap!(16, 10, 18).progress(), 0.75);
+        let empty = WrapNum {
+            value: 5,
+            min: 5,
+            max: 5,
+            inclusive: false,
+        };
+        assert_eq!(empty.progress(), 0.0);
+    }
+
+    #[test]
+    fn rotate_past_length() {
+        let mut items = [1, 2, 3, 4, 5];
+        rotate_by(&mut items, wrap!(7, 0, 20));
+        assert_eq!(items, [3, 4, 5, 1, 2]);
+        rotate_by(&mut items, wrap!(5, 0, 20));
+        assert_eq!(items, [3, 4, 5, 1, 2]);
+    }
+
+    #[test]
+    fn signed_delta_policy() {
+        let position: WrapNum<u32> = wrap!(2, 0, 10);
+        assert_eq!(position.checked_add_signed(-5).unwrap(), 7);
+        assert_eq!(position.checked_add_signed(10).unwrap(), 2);
+        assert_eq!(
+            position.checked_add_signed(11),
+            Err(WrapNumError::DeltaTooLarge {
+                delta: 11,
+                limit: 10
+            })
+        );
+        assert_eq!(position.checked_add_signed_within(-25, 30).unwrap(), 7);
+    }
+
+    #[test]
+    fn range_and_width() {
+        let dial = wrap!(7, 5, 12);
+        assert_eq!(dial.range(), 5..12);
+        assert_eq!(dial.width(), 7);
+    }
+
+    #[test]
+    fn contains_bounds() {
+        let dial = wrap!(7, 5, 12);
+        assert!(!dial.contains(4));
+        assert!(dial.contains(5));
+        assert!(dial.contains(11));
+        assert!(!dial.contains(12));
+        assert!(!dial.contains_inclusive(4));
+        assert!(dial.contains_inclusive(5));
+        assert!(dial.contains_inclusive(12));
+        assert!(!dial.contains_inclusive(13));
+
+        let written = wrap!(7, (5)..=(30));
+        assert!(written.contains_inclusive(31));
+        assert!(written.contains(30));
+        assert!(!written.contains(31));
+    }
+
+    #[test]
+    fn deltas_across_seam() {
+        assert_eq!(deltas(&[8, 10, 1, 1, 4], 0, 12), vec![2, 3, 0, 3]);
+        assert!(deltas(&[3], 0, 12).is_empty());
+    }
+
+    #[test]
+    fn epoch_from_parts() {
+        let mut odometer = EpochWrapNum::from_value_and_epoch(8, 41, 0, 10);
+        odometer.increment();
+        assert_eq!((odometer.num.value, odometer.epoch), (9, 41));
+        odometer.increment();
+        assert_eq!((odometer.num.value, odometer.epoch), (0, 42));
+        odometer.increment();
+        assert_eq!((odometer.num.value, odometer.epoch), (1, 42));
+    }
+
+    #[test]
+    fn has_map_indexing() {
+        let handlers: HashMap<u8, &str> = [(0, "idle"), (1, "running"), (2, "stopped")].into();
+        let mut state: WrapNum<u8> = wrap!(3);
+        assert_eq!(handlers[state], "idle");
+        state += 2;
+        assert_eq!(handlers[state], "stopped");
+        state += 2;
+        assert_eq!(handlers[state], "running");
+    }
+
+    #[test]
+    fn set_and_try_set() {
+        let mut dial = wrap!(7, 5, 12);
+        dial.set(10);
+        assert_eq!(dial, 10);
+        dial.set(14);
+        assert_eq!(dial, 7);
+
+        assert_eq!(dial.try_set(4), Err(OutOfRange::BelowMin));
+        assert_eq!(dial.try_set(12), Err(OutOfRange::AboveMax));
+        assert_eq!(dial, 7);
+        assert_eq!(dial.try_set(11), Ok(()));
+        assert_eq!(dial, 11);
+    }
+
+    #[test]
+    fn detents_around_ring() {
+        let knob = |v: u32| WrapNum::new_min_max(v, 0, 360).detent(8);
+        assert_eq!(knob(0), 0);
+        assert_eq!(knob(22), 0);
+        assert_eq!(knob(23), 1);
+        assert_eq!(knob(180), 4);
+        assert_eq!(knob(337), 7);
+        assert_eq!(knob(338), 0);
+        assert_eq!(knob(359), 0);
+    }
+
+    #[test]
+    fn shift_window() {
+        let mut window: WrapNum<u8> = wrap!(12, 10, 20);
+        window.shift_bounds(5);
+        assert!(window.total_eq(&wrap!(17, 15, 25)));
+        window.shift_bounds(250);
+        assert!(window.total_eq(&wrap!(247, 245, 255)));
+
+        let mut signed: WrapNum<i8> = wrap!(-100, -110, -90);
+        signed.shift_bounds(-50);
+        assert!(signed.total_eq(&wrap!(-118, -128, -108)));
+
+        let mut positive: WrapNum<i8> = wrap!(105, 100, 110);
+        positive.shift_bounds(-5);
+        assert!(positive.total_eq(&wrap!(100, 95, 105)));
+        positive.shift_bounds(100);
+        assert!(positive.total_eq(&wrap!(122, 117, 127)));
+
+        let mut negative: WrapNum<i8> = wrap!(-100, -110, -90);
+        negative.shift_bounds(5);
+        assert!(negative.total_eq(&wrap!(-95, -105, -85)));
+        negative.shift_bounds(-100);
+        assert!(negative.total_eq(&wrap!(-118, -128, -108)));
+    }
+
+    #[test]
+    fn builder_chains() {
+        let dial = WrapNum::builder(7).min(5).max(12).build().unwrap();
+        assert!(dial.total_eq(&wrap!(7, 5, 12)));
+        let default_bounds = WrapNum::builder(7u8).build().unwrap();
+        assert!(default_bounds.total_eq(&wrap!(7, 0, 255)));
+
+        assert_eq!(
+            WrapNum::builder(7).min(12).max(5).build(),
+            Err(BuildError::OutOfRange(OutOfRange::EmptyRange))
+        );
+        assert_eq!(
+            WrapNum::builder(4).min(5).max(12).build(),
+            Err(BuildError::OutOfRange(OutOfRange::BelowMin))
+        );
+        assert_eq!(
+            WrapNum::builder(12).min(5).max(12).build(),
+            Err(BuildError::OutOfRange(OutOfRange::AboveMax))
+        );
+    }
+
+    #[test]
+    fn arc_membership() {
+        let at = |v: u32| WrapNum::new_min_max(v, 0, 12);
+        assert!(at(3).on_arc(2, 5));
+        assert!(at(2).on_arc(2, 5));
+        assert!(at(5).on_arc(2, 5));
+        assert!(!at(6).on_arc(2, 5));
+        assert!(!at(0).on_arc(2, 5));
+
+        assert!(at(11).on_arc(10, 2));
+        assert!(at(0).on_arc(10, 2));
+        assert!(at(2).on_arc(10, 2));
+        assert!(!at(5).on_arc(10, 2));
+    }
+
+    #[test]
+    fn float_phase() {
+        use std::f32::consts::TAU;
+
+        let mut phase = wrap!(0.0f32, 0.0, TAU);
+        phase = phase.add_float(4.0).add_float(4.0);
+        assert!((phase.value - (8.0 - TAU)).abs() < 1e-5);
+
+        // 8 - TAU - 2 is just below zero, so it wraps back up to 6.
+        phase = phase.sub_float(2.0);
+        assert!((phase.value - 6.0).abs() < 1e-5);
+
+        phase = phase.sub_float(3.0 * TAU);
+        assert!((phase.value - 6.0).abs() < 1e-4);
+        assert!(phase.value >= 0.0 && phase.value < TAU);
+    }
+
+    #[test]
+    fn into_f64() {
+        assert_eq!(f64::from(wrap!(7, 0, 12)), 7.0);
+        assert_eq!(f64::from(wrap!(-3i8, -10, 10)), -3.0);
+        assert_eq!(f64::from(wrap!(200u64, 100, 1 << 40)), 200.0);
+    }
+
+    #[test]
+    fn tracking_laps() {
+        let mut odometer: WrapNum<i32> = wrap!(8, 0, 10);
+        assert_eq!(odometer.add_tracking(1), 0);
+        assert_eq!(odometer, 9);
+        assert_eq!(odometer.add_tracking(1), 1);
+        assert_eq!(odometer, 0);
+        assert_eq!(odometer.add_tracking(35), 3);
+        assert_eq!(odometer, 5);
+        assert_eq!(odometer.add_tracking(-5), 0);
+        assert_eq!(odometer, 0);
+        assert_eq!(odometer.add_tracking(-1), -1);
+        assert_eq!(odometer, 9);
+        assert_eq!(odometer.add_tracking(-29), -2);
+        assert_eq!(odometer, 0);
+    }
+
+    #[test]
+    fn checked_powers() {
+        let base: WrapNum<u8> = wrap!(3, 1, 100);
+        assert_eq!(base.checked_pow(4).unwrap(), 81);
+        assert_eq!(base.checked_pow(0).unwrap(), 1);
+        assert!(base.checked_pow(5).is_none());
+        assert!(base.checked_pow(6).is_none());
+    }
+
+    #[test]
+    fn pairwise_distances() {
+        let matrix = distance_matrix(&[1, 5, 11], 0, 12);
+        assert_eq!(matrix, vec![vec![0, 4, 2], vec![4, 0, 6], vec![2, 6, 0]]);
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(row[i], 0);
+            for (j, &d) in row.iter().enumerate() {
+                assert_eq!(d, matrix[j][i]);
+            }
+        }
+    }
+
+    #[test]
+    fn macro_step() {
+        let mut hours = wrap!(0, 0, 12; step = 3);
+        assert!(hours.num.total_eq(&wrap!(0, 0, 12)));
+        assert_eq!(hours.step, 3);
+        hours.increment();
+        assert_eq!(hours.num, 3);
+        assert_eq!(hours.take(5).collect::<Vec<_>>(), vec![3, 6, 9, 0, 3]);
+
+        let short = wrap!(10; step = 4);
+        assert!(short.num.total_eq(&wrap!(0, 0, 10)));
+        let offset = wrap!(2, 10; step = 4);
+        assert!(offset.num.total_eq(&wrap!(2, 0, 10)));
+        assert_eq!(offset.step, 4);
+    }
+
+    #[test]
+    fn deref_to_value() {
+        let flags: WrapNum<u8> = wrap!(0b1011, 0, 16);
+        assert_eq!(flags.count_ones(), 3);
+        assert_eq!(*flags + 1, 12);
+    }
+
+    #[test]
+    fn signed_to_unsigned_range() {
+        let centered: WrapNum<i16> = wrap!(-250, -1000, 1000);
+        let shifted = centered.to_unsigned_range();
+        assert!(shifted.total_eq(&wrap!(750, 0, 2000)));
+        assert_eq!(shifted.progress(), centered.progress());
+    }
+
+    #[test]
+    fn try_from_parts() {
+        let dial = WrapNum::try_from((7, 5, 12)).unwrap();
+        assert!(dial.total_eq(&wrap!(7, 5, 12)));
+        assert_eq!(WrapNum::try_from((4, 5, 12)), Err(OutOfRange::BelowMin));
+        assert_eq!(WrapNum::try_from((12, 5, 12)), Err(OutOfRange::AboveMax));
+        assert_eq!(WrapNum::try_from((5, 5, 5)), Err(OutOfRange::EmptyRange));
+        assert_eq!(WrapNum::try_from((7, 12, 5)), Err(OutOfRange::EmptyRange));
+    }
+
+    #[test]
+    fn skip_forbidden() {
+        let slot = wrap!(2, 0, 5);
+        assert_eq!(slot.next_skipping(3), 4);
+        assert_eq!(slot.next_skipping(1), 3);
+        assert_eq!(wrap!(4, 0, 5).next_skipping(0), 1);
+        assert_eq!(wrap!(3, 0, 5).next_skipping(0), 4);
+        assert_eq!(wrap!(0, 0, 1).next_skipping(0), 0);
+    }
+
+    #[test]
+    fn wrapped_result_either_side() {
+        assert_eq!(WrapNum::wrapped_result(12u32, 5, 10), 7);
+        assert_eq!(WrapNum::wrapped_result(27u32, 5, 10), 7);
+        assert_eq!(WrapNum::wrapped_result(2u32, 5, 10), 7);
+        assert_eq!(WrapNum::wrapped_result(0u32, 5, 10), 5);
+        assert_eq!(WrapNum::wrapped_result(-1i32, 0, 12), 11);
+        assert_eq!(WrapNum::wrapped_result(-24i32, 0, 12), 0);
+        assert_eq!(WrapNum::wrapped_result(-30i32, -5, 5), 0);
+
+        let mut dial = wrap!(7, 5, 12);
+        dial.set(1);
+        assert_eq!(dial, 8);
+    }
+
+    #[test]
+    fn parse_radix() {
+        let reg = WrapNum::<u32>::from_str_radix("ff", 16, 0, 256).unwrap();
+        assert!(reg.total_eq(&wrap!(255, 0, 256)));
+        let wrapped = WrapNum::<u32>::from_str_radix("100000101", 2, 0, 256).unwrap();
+        assert_eq!(wrapped, 5);
+        assert_eq!(
+            WrapNum::<u32>::from_str_radix("12", 2, 0, 256),
+            Err(WrapNumError::Parse)
+        );
+        assert_eq!(
+            WrapNum::<u32>::from_str_radix("5", 10, 3, 3),
+            Err(WrapNumError::EmptyRange)
+        );
+    }
+
+    #[test]
+    fn clock_abs_diff() {
+        let at = |v: u32| WrapNum::new_min_max(v, 0, 12);
+        assert_eq!(at(3).wrapping_abs_diff(at(3)), 0);
+        assert_eq!(at(1).wrapping_abs_diff(at(4)), 3);
+        assert_eq!(at(4).wrapping_abs_diff(at(1)), 3);
+        assert_eq!(at(11).wrapping_abs_diff(at(1)), 2);
+        assert_eq!(at(0).wrapping_abs_diff(at(6)), 6);
+        assert_eq!(at(9).wrapping_abs_diff(at(3)), 6);
+    }
+
+    #[test]
+    fn ring_segments() {
+        let clock = wrap!(0, 0, 12);
+        assert_eq!(clock.segments(4), vec![(0, 3), (3, 6), (6, 9), (9, 12)]);
+        let odd = wrap!(5, 5, 15);
+        assert_eq!(odd.segments(3), vec![(5, 9), (9, 12), (12, 15)]);
+        assert!(odd.segments(0).is_empty());
+    }
+
+    #[test]
+    fn assign_wrapnum() {
+        let mut hour = wrap!(9, 0, 12);
+        let shift = wrap!(5, 0, 12);
+        hour += shift;
+        assert!(hour.total_eq(&wrap!(2, 0, 12)));
+        hour -= shift;
+        assert!(hour.total_eq(&wrap!(9, 0, 12)));
+        hour -= wrap!(11, 0, 12);
+        assert_eq!(hour, 10);
+    }
+
+    #[test]
+    fn return_to_center() {
+        let mut stick: WrapNum<i8> = wrap!(2, -5, 5);
+        stick.toward_zero();
+        assert_eq!(stick, 1);
+        stick.toward_zero();
+        stick.toward_zero();
+        assert_eq!(stick, 0);
+
+        stick.set(-2);
+        stick.toward_zero();
+        assert_eq!(stick, -1);
+
+        let mut above: WrapNum<i8> = wrap!(5, 5, 10);
+        above.toward_zero();
+        assert_eq!(above, 9);
+    }
+
+    #[test]
+    fn map_slice_in_place() {
+        let mut dials = [wrap!(3, 0, 5), wrap!(3, 0, 12), wrap!(7, 5, 10)];
+        map_in_place(&mut dials, |v| v * 2);
+        assert!(dials[0].total_eq(&wrap!(1, 0, 5)));
+        assert!(dials[1].total_eq(&wrap!(6, 0, 12)));
+        assert!(dials[2].total_eq(&wrap!(9, 5, 10)));
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` must be greater than `min`.")]
+    fn zero_width_range() {
+        wrap!(5, 5, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` must be greater than `min`.")]
+    fn zero_width_max() {
+        wrap!(0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "`value` must be less than `max`.")]
+    fn value_at_max() {
+        wrap!(5u32, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "`value` must be less than `max`.")]
+    fn value_at_min_max() {
+        wrap!(12u32, 0, 12);
+    }
+
+    #[test]
+    fn add_from_last_value() {
+        let mut dial = wrap!(11u32, 0, 12);
+        dial += 1;
+        assert_eq!(dial, 0);
+        assert_eq!(wrap!(4u32, 5) + 1, 0);
+    }
+
+    #[test]
+    fn map_doubling() {
+        let dial = wrap!(4, 0, 12);
+        assert!(dial.map(|v| v * 2).total_eq(&wrap!(8, 0, 12)));
+        assert!(dial
+            .map(|v| v * 2)
+            .map(|v| v * 2)
+            .total_eq(&wrap!(4, 0, 12)));
+    }
+
+    #[test]
+    fn checked_signed_sub() {
+        let position: WrapNum<u32> = wrap!(4, 2, 10);
+        assert_eq!(position.checked_sub_signed(2).unwrap(), 2);
+        assert_eq!(position.checked_sub_signed(-5).unwrap(), 9);
+        assert!(position.checked_sub_signed(3).is_none());
+        assert!(position.checked_sub_signed(-6).is_none());
+    }
 
     #[test]
-    fn make_usize() {
-        let mut result = wrap!(50);
-        for _ in 0..60 {
-            result += 1;
-            println!("{}", result);
+    fn clock_strings() {
+        assert_eq!(wrap!(0, 0, 86_400).clock_string(), "00:00:00");
+        assert_eq!(wrap!(43_200, 0, 86_400).clock_string(), "12:00:00");
+        assert_eq!(wrap!(86_399, 0, 86_400).clock_string(), "23:59:59");
+        assert_eq!(wrap!(90_061, 0, 100_000).clock_string(), "01:01:01");
+    }
+
+    #[test]
+    fn bounded_and_zero() {
+        let top = WrapNum::<u32>::max_value();
+        assert!(top.total_eq(&WrapNum::new_min_max(u32::MAX - 1, 0, u32::MAX)));
+        let bottom = WrapNum::<u32>::min_value();
+        assert!(bottom.total_eq(&WrapNum::new_min_max(0, 0, u32::MAX)));
+
+        let nothing = WrapNum::<u8>::zero();
+        assert!(nothing.is_zero());
+        assert!(!wrap!(3, 0, 12).is_zero());
+        assert_eq!(wrap!(3, 0, 12) + nothing, 3);
+    }
+
+    #[test]
+    fn mirrored_indices() {
+        let mirrored: Vec<_> = (-7..10).map(|i| mirror_index(i, 3)).collect();
+        assert_eq!(
+            mirrored,
+            vec![0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 2]
+        );
+    }
+
+    #[test]
+    fn from_ranges() {
+        assert!(WrapNum::from_range(0..12).total_eq(&wrap!(0, 0, 12)));
+        assert!(WrapNum::from_range(5..10).total_eq(&wrap!(5, 5, 10)));
+        assert!(WrapNum::from_range_inclusive(0..=11).total_eq(&wrap!(0, 0, 12)));
+        let top = WrapNum::from_range_inclusive(250u8..=u8::MAX);
+        assert_eq!(
+            (top.value, top.min, top.max, top.inclusive),
+            (250, 250, 255, true)
+        );
+    }
+
+    #[test]
+    fn sort_around_pivot() {
+        let mut events: Vec<WrapNum<u32>> = [2, 11, 7, 9, 0, 8]
+            .into_iter()
+            .map(|v| WrapNum::new_min_max(v, 0, 12))
+            .collect();
+        events.sort_by(|a, b| a.compare_from(b.value, 8));
+        let order: Vec<_> = events.iter().map(|e| e.value).collect();
+        assert_eq!(order, vec![8, 9, 11, 0, 2, 7]);
+    }
+
+    #[test]
+    fn epoch_saturates() {
+        let mut odometer = EpochWrapNum::from_value_and_epoch(1, u64::MAX - 1, 0, 2);
+        odometer.increment();
+        assert_eq!(odometer.epoch, u64::MAX);
+        odometer.increment();
+        odometer.increment();
+        assert_eq!((odometer.num.value, odometer.epoch), (0, u64::MAX));
+    }
+
+    #[test]
+    fn add_signed_large_deltas() {
+        let position: WrapNum<u32> = wrap!(2, 0, 5);
+        assert_eq!(position.wrapping_add_signed(1), 3);
+        assert_eq!(position.wrapping_add_signed(-3), 4);
+        assert_eq!(position.wrapping_add_signed(23), 0);
+        assert_eq!(position.wrapping_add_signed(-23i64), 4);
+        assert_eq!(position.wrapping_add_signed(i8::MIN), 4);
+        assert_eq!(position.wrapping_add_signed(i32::MAX), 4);
+    }
+
+    #[test]
+    fn torus_corner() {
+        let (x, y) = (wrap!(0, 0, 8), wrap!(0, 0, 6));
+        assert_eq!(torus_neighbors(x, y), [(0, 5), (0, 1), (7, 0), (1, 0)]);
+        let (x, y) = (wrap!(7, 0, 8), wrap!(5, 0, 6));
+        assert_eq!(torus_neighbors(x, y), [(7, 4), (7, 0), (6, 5), (0, 5)]);
+    }
+
+    #[test]
+    fn wrap_bare_values() {
+        assert_eq!(WrapNum::wrap(1_000_003u32, 0, 12), 7);
+        assert_eq!(WrapNum::wrap(3u32, 10, 20), 13);
+        assert_eq!(WrapNum::wrap(-1_000_001i32, -6, 6), -5);
+        assert_eq!(WrapNum::wrap(999i64, -6, 6), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` must be greater than `min`.")]
+    fn wrap_bare_empty_range() {
+        WrapNum::wrap(5u32, 3, 3);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Mismatched bounds: left is `0..12` but right is `0..24`.")]
+    fn mismatched_sub() {
+        let _ = wrap!(3, 0, 12) - wrap!(3, 0, 24);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Mismatched bounds: left is `0..12` but right is `0..24`.")]
+    fn mismatched_add_assign() {
+        let mut dial = wrap!(3, 0, 12);
+        dial += wrap!(20, 0, 24);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Mismatched bounds: left is `0..12` but right is `0..24`.")]
+    fn mismatched_sub_assign() {
+        let mut dial = wrap!(3, 0, 12);
+        dial -= wrap!(20, 0, 24);
+    }
+
+    #[test]
+    fn zero_with_any_bounds() {
+        let mut dial = wrap!(7, 5, 12);
+        dial += WrapNum::zero();
+        dial -= WrapNum::zero();
+        assert_eq!(dial - WrapNum::zero(), 7);
+        assert_eq!(WrapNum::zero() + dial, 7);
+    }
+
+    #[test]
+    fn value_or_fallback() {
+        let mut dial = wrap!(7, 5, 12);
+        assert_eq!(dial.value_or(0), 7);
+        dial.value = 40;
+        assert_eq!(dial.value_or(0), 0);
+        dial.value = 7;
+        dial.max = 5;
+        assert_eq!(dial.value_or(0), 0);
+    }
+
+    #[test]
+    fn split_across_wrap() {
+        let cursor = wrap!(3, 0, 5);
+        assert_eq!(cursor.split_add(1), (1, 0));
+        assert_eq!(cursor.split_add(2), (2, 0));
+        assert_eq!(cursor.split_add(4), (2, 2));
+        assert_eq!(cursor.split_add(12), (2, 10));
+        assert_eq!(wrap!(6, 5, 10).split_add(6), (4, 2));
+    }
+
+    #[test]
+    fn clamped_assignment() {
+        let mut dial = wrap!(7, 5, 12);
+        dial.clamped_set(2);
+        assert_eq!(dial, 5);
+        dial.clamped_set(12);
+        assert_eq!(dial, 11);
+        dial.clamped_set(100);
+        assert_eq!(dial, 11);
+        dial.clamped_set(8);
+        assert_eq!(dial, 8);
+    }
+
+    #[test]
+    fn backward_walk() {
+        let history: Vec<u32> = wrap!(2, 0, 4).iter_rev().take(7).collect();
+        assert_eq!(history, vec![2, 1, 0, 3, 2, 1, 0]);
+        let offset: Vec<u32> = wrap!(6, 5, 8).iter_rev().take(4).collect();
+        assert_eq!(offset, vec![6, 5, 7, 6]);
+    }
+
+    #[test]
+    fn checked_constructors() {
+        assert!(WrapNum::checked_new_max(3, 10)
+            .unwrap()
+            .total_eq(&wrap!(3, 10)));
+        assert!(WrapNum::checked_new_max(11, 10).is_none());
+        assert!(WrapNum::checked_new_max(10, 10).is_none());
+        assert!(WrapNum::checked_new_max(0, 0).is_none());
+
+        let dial = WrapNum::checked_new_min_max(7, 5, 12).unwrap();
+        assert!(dial.total_eq(&wrap!(7, 5, 12)));
+        assert!(WrapNum::checked_new_min_max(13, 5, 12).is_none());
+        assert!(WrapNum::checked_new_min_max(12, 5, 12).is_none());
+        assert!(WrapNum::checked_new_min_max(4, 5, 12).is_none());
+        assert!(WrapNum::checked_new_min_max(5, 5, 5).is_none());
+        assert!(WrapNum::checked_new_min_max(7, 12, 5).is_none());
+    }
+
+    #[test]
+    fn bitwise_rewraps() {
+        let flags = wrap!(7, 5, 12);
+        assert!((flags & 0b0011).total_eq(&wrap!(10, 5, 12)));
+        assert!((flags | 0b1000).total_eq(&wrap!(8, 5, 12)));
+        assert!((flags ^ 0b0001).total_eq(&wrap!(6, 5, 12)));
+        assert!((flags | 0b1100).total_eq(&wrap!(8, 5, 12)));
+    }
+
+    #[test]
+    fn shifts_rewrap() {
+        let lfsr: WrapNum<u8> = wrap!(3, 1, 10);
+        assert!((lfsr << 1).total_eq(&wrap!(6, 1, 10)));
+        assert!((lfsr << 2).total_eq(&wrap!(3, 1, 10)));
+        assert!((wrap!(200u8, 0, 255) << 1).total_eq(&wrap!(144, 0, 255)));
+        assert!((lfsr << 8).total_eq(&wrap!(9, 1, 10)));
+        assert!((wrap!(9u8, 1, 10) >> 1).total_eq(&wrap!(4, 1, 10)));
+        assert!((wrap!(9u8, 1, 10) >> 4).total_eq(&wrap!(9, 1, 10)));
+        assert!((wrap!(9u8, 1, 10) >> 40).total_eq(&wrap!(9, 1, 10)));
+    }
+
+    #[test]
+    fn tick_in_place() {
+        let mut frame: WrapNum<u32> = wrap!(1, 0, 3);
+        assert_eq!(frame.tick(), 2);
+        assert_eq!(frame.tick(), 0);
+        assert_eq!(frame, 0);
+        assert_eq!(frame.tick_back(), 2);
+        assert_eq!(frame, 2);
+        assert_eq!(frame.tick_back(), 1);
+    }
+
+    #[test]
+    fn back_to_usize() {
+        let pointer: WrapNum<usize> = wrap!(0, 29_999) + 29_998;
+        assert_eq!(usize::from(pointer), 29_998);
+        assert_eq!(pointer.into_inner(), 29_998);
+        assert_eq!(isize::from(wrap!(-4isize, -10, 10)), -4);
+    }
+
+    #[test]
+    fn display_padding() {
+        let counter = wrap!(7, 0, 12);
+        assert_eq!(format!("{:>4}", counter), "   7");
+        assert_eq!(format!("{:04}", counter), "0007");
+        assert_eq!(format!("{:*<3}|", counter), "7**|");
+        assert_eq!(format!("{:+}", counter), "+7");
+    }
+
+    #[test]
+    fn radix_formatting() {
+        let reg: WrapNum<u32> = wrap!(0xbeef, 0, 0x10000);
+        assert_eq!(format!("{:x}", reg), "beef");
+        assert_eq!(format!("{:#X}", reg), "0xBEEF");
+        assert_eq!(format!("{:#010b}", wrap!(5u8, 0, 8)), "0b00000101");
+        assert_eq!(format!("{:o}", wrap!(8u8, 0, 9)), "10");
+    }
+
+    #[test]
+    fn mirror_on_clock() {
+        let at = |v: u32| WrapNum::new_min_max(v, 0, 12);
+        assert_eq!(at(3).mirror(), 9);
+        assert_eq!(at(9).mirror(), 3);
+        assert_eq!(at(6).mirror(), 6);
+        assert_eq!(at(11).mirror(), 1);
+        assert_eq!(at(0).mirror(), 0);
+        assert_eq!(wrap!(6, 5, 12).mirror(), 11);
+        assert_eq!(wrap!(5, 5, 12).mirror(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` must be greater than `min`.")]
+    fn zero_max_new() {
+        WrapNum::<u32>::new(0);
+    }
+
+    #[test]
+    fn rescale_keeps_fraction() {
+        let mut ring: WrapNum<u32> = wrap!(3, 0, 10);
+        ring.rescale(0, 20);
+        assert!(ring.total_eq(&wrap!(6, 0, 20)));
+        ring.rescale(0, 10);
+        assert!(ring.total_eq(&wrap!(3, 0, 10)));
+        ring.rescale(100, 103);
+        assert!(ring.total_eq(&wrap!(100, 100, 103)));
+
+        let mut odd: WrapNum<i32> = wrap!(7, 5, 15);
+        odd.rescale(-5, 0);
+        assert!(odd.total_eq(&wrap!(-4, -5, 0)));
+    }
+
+    #[test]
+    fn has_str_indexing() {
+        let text = "hello";
+        let mut cursor = wrap!(4, 0, 5);
+        assert_eq!(text[cursor], b'o');
+        cursor += 1;
+        assert_eq!(text[cursor], b'h');
+        assert_eq!(String::from(text)[cursor + 1], b'e');
+    }
+
+    #[test]
+    fn shortest_direction() {
+        let dial = wrap!(2, 0, 12);
+        assert_eq!(dial.direction_to(2), Ordering::Equal);
+        assert_eq!(dial.direction_to(4), Ordering::Greater);
+        assert_eq!(dial.direction_to(0), Ordering::Less);
+        assert_eq!(dial.direction_to(10), Ordering::Less);
+        assert_eq!(dial.direction_to(7), Ordering::Greater);
+        assert_eq!(dial.direction_to(8), Ordering::Equal);
+    }
+
+    #[test]
+    fn parts_round_trip() {
+        let dial = wrap!(7, 5, 12);
+        assert_eq!(dial.into_parts(), (7, 5, 12));
+        assert!(WrapNum::from_parts(dial.into_parts())
+            .unwrap()
+            .total_eq(&dial));
+        assert_eq!(WrapNum::from_parts((12, 5, 12)), Err(OutOfRange::AboveMax));
+    }
+
+    #[test]
+    fn assign_large_steps() {
+        let mut cell: WrapNum<u8> = wrap!(250, 0, 255);
+        cell += 200;
+        assert_eq!(cell, 195);
+        cell += u8::MAX;
+        assert_eq!(cell, 195);
+        cell -= 254;
+        assert_eq!(cell, 196);
+        cell -= u8::MAX;
+        assert_eq!(cell, 196);
+
+        let mut dial: WrapNum<u32> = wrap!(2, 0, 5);
+        dial += 5 * 7 + 4;
+        assert_eq!(dial, 1);
+        dial -= 5 * 9 + 3;
+        assert_eq!(dial, 3);
+        dial -= 4;
+        assert_eq!(dial, 4);
+        assert_eq!(dial - 12, 2);
+        assert_eq!(dial + 1_000_001, 0);
+
+        let mut signed: WrapNum<i32> = wrap!(0, -3, 3);
+        signed += -14;
+        assert_eq!(signed, -2);
+        signed -= -13;
+        assert_eq!(signed, -1);
+    }
+
+    #[test]
+    fn batched_deltas() {
+        let start: WrapNum<i32> = wrap!(3, 0, 10);
+        let deltas = [4, -9, 25, 7, -1, 0];
+        let mut one_by_one = start;
+        for &delta in &deltas {
+            one_by_one += delta;
         }
+        assert!(start.apply_deltas(&deltas).total_eq(&one_by_one));
+        assert_eq!(one_by_one, 9);
+        assert!(start.apply_deltas(&[]).total_eq(&start));
     }
 
     #[test]
-    fn months() {
-        let mut months = wrap!(11);
-        for _ in 0..11 {
-            months += 1;
+    fn iterate_all_values() {
+        let states: Vec<u8> = wrap!(3, 0, 7).into_iter().collect();
+        assert_eq!(states, vec![0, 1, 2, 3, 4, 5, 6]);
+        let mut seen = Vec::new();
+        for v in wrap!(-1i8, -2, 2) {
+            seen.push(v);
         }
-        assert_eq!(months.value, 0);
+        assert_eq!(seen, vec![-2, -1, 0, 1]);
     }
 
     #[test]
-    fn custom_min() {
-        let mut mins = wrap!(5, 5, 7);
-        mins += 3;
-        // 5+1 = 6
-        // 6+1 = 7 (wrapped) = 5
-        // 5+1 = 6
-        assert_eq!(mins.value, 6);
+    fn len_matches_iteration() {
+        let ring = wrap!(6, 5, 12);
+        assert_eq!(ring.len(), 7);
+        assert_eq!(ring.into_iter().count(), ring.len());
+        assert!(!ring.is_empty());
+        let hollow = WrapNum {
+            value: 5,
+            min: 5,
+            max: 5,
+            inclusive: false,
+        };
+        assert!(hollow.is_empty());
     }
 
     #[test]
-    fn can_convert() {
-        let mut mins = wrap!(=5);
-        mins += 5 as u16;
+    fn index_extraction() {
+        let slot: WrapNum<u8> = wrap!(9, 0, 16);
+        let table = [0usize; 16];
+        let lookup = |idx: usize| table.get(idx).is_some();
+        assert_eq!(slot.as_index(), 9usize);
+        assert!(lookup(slot.as_index()));
+        assert_eq!(slot.try_as_index(), Some(9));
+        assert_eq!(wrap!(-1i8, -5, 5).try_as_index(), None);
     }
 
     #[test]
-    fn has_indexing() {
-        let here = wrap!(5);
-        let oh = vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
-        assert_eq!(oh[here], 10);
+    #[should_panic(expected = "Failed to convert index to usize")]
+    fn negative_index() {
+        wrap!(-1i8, -5, 5).as_index();
     }
 
     #[test]
-    fn are_equals() {
-        let mut here = wrap!(6);
-        here += 5;
-        println!("{}", here);
-        let there = wrap!(50);
-        assert_eq!(here, there + 5);
+    fn same_dial_new_position() {
+        let dial = wrap!(13, 10, 20);
+        let moved = dial.with_value(27);
+        assert_eq!((moved.value, moved.min, moved.max), (17, 10, 20));
+        assert_eq!(dial.with_value(12).value, 12);
+        assert_eq!(dial.value, 13);
     }
 
     #[test]
-    fn into_integer() {
-        let here = wrap!(420, 0, 69420);
-        let hmm: WrapNum<u32> = 420.into();
-        let as_u32 = u32::from(here);
+    fn same_range_sub() {
+        let diff = wrap!(3, 0, 12) - wrap!(5, 0, 12);
+        assert_eq!((diff.value, diff.min, diff.max), (10, 0, 12));
+        assert_eq!((wrap!(18u8, 10, 20) - wrap!(11u8, 10, 20)).value, 17);
+    }
+
+    #[test]
+    fn steps_ahead() {
+        let card: WrapNum<u8> = wrap!(50, 0, 52);
+        for n in [0u8, 1, 2, 51, 52, 53, 104, 255] {
+            let mut stepped = card;
+            for _ in 0..n {
+                stepped += 1;
+            }
+            assert_eq!(card.nth_after(n), stepped.value);
+        }
+        assert_eq!(card.nth_after(255), 45);
+        assert_eq!(card, 50);
+    }
+
+    #[test]
+    fn widened_mul() {
+        let residue: WrapNum<u8> = wrap!(200, 0, 251);
+        assert_eq!(residue.checked_mul(200).unwrap(), 91);
+        assert_eq!(wrap!(-7i8, -10, 10).checked_mul(100).unwrap(), 0);
+        assert_eq!(wrap!(3i64, 0, 7).checked_mul(i64::MAX).unwrap(), 0);
+        assert!(wrap!(2u128, 0, 7).checked_mul(u128::MAX).is_none());
+    }
+
+    #[test]
+    fn as_ref_and_borrow() {
+        fn doubled<R: AsRef<u32>>(n: R) -> u32 {
+            n.as_ref() * 2
+        }
+        fn tripled<B: Borrow<u32>>(n: B) -> u32 {
+            n.borrow() * 3
+        }
+        assert_eq!(doubled(wrap!(7u32, 0, 10)), 14);
+        assert_eq!(tripled(wrap!(7u32, 0, 10)), 21);
+        assert_eq!(tripled(7u32), 21);
+    }
+
+    #[test]
+    fn like_template() {
+        let template = wrap!(100u16, 100, 200);
+        let made: Vec<_> = [150, 250, 99]
+            .into_iter()
+            .map(|value| WrapNum::like(&template, value))
+            .collect();
+        assert_eq!(made, vec![150, 150, 199]);
+        assert!(made.iter().all(|num| num.min == 100 && num.max == 200));
+    }
+
+    #[test]
+    fn cycle_from_middle() {
+        let seats = vec!['a', 'b', 'c', 'd', 'e'];
+        let order: Vec<_> = cycle_from(&seats, wrap!(3, 0, seats.len())).collect();
+        assert_eq!(order, vec![&'d', &'e', &'a', &'b', &'c']);
+        assert_eq!(
+            cycle_from(&seats, wrap!(0, 0, seats.len())).count(),
+            seats.len()
+        );
+        assert_eq!(cycle_from(&[] as &[u8], wrap!(0, 0, 1)).count(), 0);
+    }
+
+    #[test]
+    fn total_ordering() {
+        let mut configs = vec![
+            wrap!(3, 0, 12),
+            wrap!(1, 0, 24),
+            wrap!(7, 5, 10),
+            wrap!(1, 0, 12),
+            wrap!(3, 0, 12),
+        ];
+        configs.sort_by(WrapNum::total_cmp);
+        configs.dedup_by(|a, b| a.total_eq(b));
+        let fields: Vec<_> = configs.iter().map(|n| (n.value, n.min, n.max)).collect();
+        assert_eq!(fields, vec![(1, 0, 12), (3, 0, 12), (1, 0, 24), (7, 5, 10)]);
+        assert_eq!(wrap!(9, 0, 12).total_cmp(&wrap!(1, 0, 24)), Ordering::Less);
+    }
+
+    #[test]
+    fn wrapped_result_near_max() {
+        assert_eq!(WrapNum::wrapped_result(i8::MAX, -100, 27), 0);
+        assert_eq!(WrapNum::wrapped_result(i8::MAX, -128, -1), -127);
+        assert_eq!(WrapNum::wrapped_result(i8::MIN, 100, 120), 112);
+        assert_eq!(WrapNum::wrapped_result(i8::MIN, -1, 126), -1);
+        assert_eq!(WrapNum::wrapped_result(u8::MAX, 0, 7), 3);
+        assert_eq!(WrapNum::wrapped_result(u8::MAX, 200, 255), 200);
+        assert_eq!(WrapNum::wrapped_result(i64::MAX, -5, 5), -3);
+        let (min, max) = (u128::MAX / 2 + 1, u128::MAX / 2 + 4);
+        assert_eq!(WrapNum::wrapped_result(u128::MAX, min, max), min + 1);
+    }
+
+    #[test]
+    fn wide_signed_ranges() {
+        assert_eq!(WrapNum::wrapped_result(i8::MAX, -100, 100), -73);
+        assert_eq!(WrapNum::wrapped_result(i8::MIN, -100, 100), 72);
+
+        let mut dial = wrap!(0i8, -100, 100);
+        dial += 1;
+        assert_eq!(dial, 1);
+        dial += i8::MIN;
+        assert_eq!(dial, 73);
+        assert_eq!(wrap!(99i8, -100, 100) + 1, -100);
+        assert_eq!(wrap!(-100i8, -100, 100) - 1, 99);
+        assert_eq!(wrap!(90i8, -100, 100) + 127, 17);
+
+        let mut byte = WrapNum::from_range_inclusive(-128i8..=127);
+        byte -= 100;
+        assert_eq!(byte, 28);
+        byte += i8::MAX;
+        assert_eq!(byte, -101);
+
+        let mut full = WrapNum::from_range_inclusive(i128::MIN..=i128::MAX);
+        full += i128::MAX;
+        assert_eq!(full, -1);
+        let mut full = wrap!(=u128::MAX);
+        full -= 1;
+        assert_eq!(full, u128::MAX);
+    }
+
+    #[test]
+    fn reset_all_fields() {
+        let mut mode = wrap!(3, 0, 4);
+        assert_eq!(mode.reset(12, 10, 20), Ok(()));
+        assert!(mode.total_eq(&wrap!(12, 10, 20)));
+
+        assert_eq!(mode.reset(5, 5, 5), Err(OutOfRange::EmptyRange));
+        assert_eq!(mode.reset(4, 5, 8), Err(OutOfRange::BelowMin));
+        assert_eq!(mode.reset(8, 5, 8), Err(OutOfRange::AboveMax));
+        assert!(mode.total_eq(&wrap!(12, 10, 20)));
+    }
+
+    #[test]
+    fn one_lap() {
+        let coprime: Vec<_> = wrap!(2, 0, 7; step = 3).cycle_once().collect();
+        assert_eq!(coprime, vec![2, 5, 1, 4, 0, 3, 6]);
+        let divisible: Vec<_> = wrap!(1, 0, 12; step = 4).cycle_once().collect();
+        assert_eq!(divisible, vec![1, 5, 9]);
+        assert_eq!(wrap!(3, 0, 5; step = 5).cycle_once().count(), 1);
+    }
+
+    #[test]
+    fn new_range_starts_at_min() {
+        let ring = WrapNum::new_range(5, 10);
+        assert!(ring.total_eq(&wrap!(5, 5, 10)));
+        assert_eq!(ring + 7, 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` must be greater than `min`.")]
+    fn new_range_empty() {
+        WrapNum::new_range(10, 5);
+    }
+
+    #[test]
+    fn normalized_bounds() {
+        let swapped = WrapNum::new_min_max_normalized(7, 10, 5);
+        assert!(swapped.total_eq(&wrap!(7, 5, 10)));
+        assert_eq!(WrapNum::new_min_max_normalized(12, 10, 5), 7);
+        assert!(WrapNum::new_min_max_normalized(3u8, 0, 4).total_eq(&wrap!(3, 0, 4)));
+    }
+
+    #[test]
+    fn combine_into_third_range() {
+        let (gear, wheel) = (wrap!(7, 0, 12), wrap!(5, 0, 8));
+        let sum = gear.combine(wheel, |a, b| a + b, 0, 10);
+        assert!(sum.total_eq(&wrap!(2, 0, 10)));
+        let product = gear.combine(wheel, |a, b| a * b, 100, 110);
+        assert!(product.total_eq(&wrap!(105, 100, 110)));
+    }
+
+    #[test]
+    fn saturating_constructor() {
+        assert!(WrapNum::saturating_new_min_max(40, 5, 10).total_eq(&wrap!(9, 5, 10)));
+        assert!(WrapNum::saturating_new_min_max(-3, 5, 10).total_eq(&wrap!(5, 5, 10)));
+        assert!(WrapNum::saturating_new_min_max(7, 5, 10).total_eq(&wrap!(7, 5, 10)));
+        assert_eq!(WrapNum::saturating_new_min_max(u8::MAX, 0, u8::MAX), 254);
+    }
+
+    #[test]
+    fn peek_landing() {
+        let pos: WrapNum<u8> = wrap!(250, 200, 255);
+        for rhs in [0u8, 3, 5, 60, 255] {
+            assert_eq!(pos.peek_add(rhs), (pos + rhs).value);
+            assert_eq!(pos.peek_sub(rhs), (pos - rhs).value);
+        }
+        assert_eq!(pos.peek_add(7), 202);
+        assert_eq!(pos.peek_sub(51), 254);
+        assert_eq!(pos, 250);
+    }
+
+    #[test]
+    fn all_range_values() {
+        let dial = wrap!(7u8, 5, 10);
+        let values = dial.range_values();
+        assert_eq!(values, vec![5, 6, 7, 8, 9]);
+        assert_eq!(values.len(), usize::from(dial.width()));
+    }
+
+    #[test]
+    fn signed_shortest_delta() {
+        let servo = wrap!(10, 0, 12);
+        assert_eq!(servo.signed_distance_to(1), 3);
+        assert_eq!(servo.signed_distance_to(7), -3);
+        assert_eq!(servo.signed_distance_to(10), 0);
+        assert_eq!(servo.signed_distance_to(4), 6);
+        assert_eq!(wrap!(4, 0, 12).signed_distance_to(10), 6);
+        assert_eq!(wrap!(2u8, 0, 5).signed_distance_to(0), -2);
+    }
+
+    #[test]
+    fn macro_value_and_range() {
+        let (min, max) = (0, 12);
+        assert!(wrap!(3, (0)..(12)).total_eq(&wrap!(3, 0, 12)));
+        assert!(wrap!(3, (min)..(max)).total_eq(&wrap!(3, 0, 12)));
+        assert!(wrap!(7, (5)..=(30)).total_eq(&wrap!(7, 5, 31)));
+        assert!(wrap!(30, (5)..=(30)).total_eq(&wrap!(30, 5, 31)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid state: `value` is outside of `min` and `max`.")]
+    fn corrupted_value_caught() {
+        let mut dial = wrap!(3, 0, 12);
+        dial.value = 40;
+        dial += 1;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid state: `value` is outside of `min` and `max`.")]
+    fn corrupted_below_min_caught() {
+        let mut dial = wrap!(3i32, 0, 12);
+        dial.value = -5;
+        dial += 1;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid state: `value` is outside of `min` and `max`.")]
+    fn corrupted_unsigned_caught() {
+        let mut dial = wrap!(3u32, 0, 12);
+        dial.value = u32::MAX;
+        dial -= 1;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid state: `value` is outside of `min` and `max`.")]
+    fn corrupted_add_tracking_caught() {
+        let mut dial = wrap!(3u32, 0, 12);
+        dial.value = 40;
+        dial.add_tracking(1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid state: `value` is outside of `min` and `max`.")]
+    fn corrupted_rescale_caught() {
+        let mut dial = wrap!(3u32, 0, 12);
+        dial.value = 40;
+        dial.rescale(0, 24);
+    }
+
+    #[test]
+    fn inclusive_at_type_max() {
+        let mut byte = wrap!(=255u8);
+        assert_eq!((byte.min, byte.max, byte.inclusive), (0, 255, true));
+        byte += 255;
+        assert!(byte.is_at_max());
+        byte += 1;
+        assert_eq!(byte, 0);
+        byte -= 1;
+        assert_eq!(byte, 255);
+        byte += 200;
+        assert_eq!(byte, 199);
+
+        let mut top = wrap!(250u8, (200)..=(255));
+        assert!(top.inclusive);
+        top += 10;
+        assert_eq!(top, 204);
+        top -= 5;
+        assert_eq!(top, 255);
+        assert_eq!(top.tick(), 200);
+        assert_eq!(top.tick_back(), 255);
+        top.set(10);
+        assert_eq!(top, 234);
+        assert_eq!(top.try_set(255), Ok(()));
+        assert_eq!(top.wrapping_add_signed(1), 200);
+        assert_eq!(top.remaining(), 1);
+
+        let mut single = WrapNum::new_min_max_inclusive(255u8, 255, 255);
+        assert!(!single.is_empty());
+        single += 7;
+        assert_eq!(single, 255);
+    }
+
+    #[test]
+    fn inclusive_ranges() {
+        assert!(wrap!(=5).total_eq(&wrap!(0, 0, 6)));
+        let mut dial = wrap!(7, (5)..=(30));
+        assert!(!dial.inclusive);
+        dial += 24;
+        assert_eq!(dial, 5);
+        assert!(WrapNum::from_range_inclusive(3..=3).total_eq(&wrap!(3, 3, 4)));
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` must not be less than `min`.")]
+    fn inclusive_backwards() {
+        WrapNum::new_min_max_inclusive(3, 3, 2);
+    }
+
+    #[test]
+    fn inclusive_range_apis() {
+        let byte = wrap!(=255u8);
+        let top = WrapNum::new_min_max_inclusive(255u8, 0, 255);
+        assert!(byte.is_inclusive());
+        assert_eq!(WrapNum::new_min_max_inclusive(0u16, 0, 255).len(), 256);
+        assert_eq!(byte.into_iter().count(), 256);
+        assert_eq!(byte.into_iter().last(), Some(255));
+        assert_eq!(byte.range_values().len(), 256);
+        assert_eq!(byte.range_inclusive(), 0..=255);
+        assert_eq!(wrap!(3, 0, 12).range_inclusive(), 0..=11);
+        assert_eq!(WrapNum::from_range_inclusive(100i8..=127).width(), 28);
+
+        assert_eq!(byte.wrapping_abs_diff(top), 1);
+        assert_eq!(byte.direction_to(128), Ordering::Equal);
+        assert_eq!(byte.direction_to(255), Ordering::Less);
+        assert_eq!(byte.direction_to(1), Ordering::Greater);
+        assert!(top.on_arc(250, 2));
+        assert!(!byte.on_arc(1, 254));
+        assert_eq!(top.compare_from(0, 250), Ordering::Less);
+
+        let near_top = WrapNum::new_min_max_inclusive(254u8, 0, 255);
+        assert_eq!(near_top.split_add(3), (2, 1));
+        assert_eq!(byte.split_add(255), (255, 0));
+        assert_eq!(byte.segments(2), vec![(0, 128), (128, 255)]);
+        assert_eq!(top.progress(), 255.0 / 256.0);
+        assert_eq!(top.reflecting_add(1), 254);
+        assert_eq!(byte.reflecting_add(255), 255);
+        assert_eq!(byte.with_value(10).reflecting_add(250), 250);
+    }
+
+    #[test]
+    fn inclusive_parts() {
+        let top = WrapNum::new_min_max_inclusive(255u8, 0, 255);
+        assert_eq!(top.into_parts_inclusive(), (255, 0..=255));
+        assert!(WrapNum::from_parts_inclusive(top.into_parts_inclusive())
+            .unwrap()
+            .total_eq(&top));
+
+        assert!(WrapNum::try_from((5u8, 0..=10))
+            .unwrap()
+            .total_eq(&wrap!(5, 0, 11)));
+        assert_eq!(
+            WrapNum::try_from((3, RangeInclusive::new(5, 4))),
+            Err(OutOfRange::EmptyRange)
+        );
+        assert_eq!(WrapNum::try_from((4, 5..=8)), Err(OutOfRange::BelowMin));
+        assert_eq!(WrapNum::try_from((9, 5..=8)), Err(OutOfRange::AboveMax));
+
+        let mut dial = wrap!(3u8, 0, 12);
+        assert_eq!(dial.reset_inclusive(255, 0, 255), Ok(()));
+        assert!(dial.total_eq(&top));
+        assert_eq!(dial.reset_inclusive(9, 5, 8), Err(OutOfRange::AboveMax));
+        assert!(dial.total_eq(&top));
+    }
+
+    #[test]
+    #[should_panic(expected = "Width does not fit in `T`")]
+    fn inclusive_width_overflow() {
+        wrap!(=255u8).width();
+    }
+
+    #[test]
+    #[should_panic(expected = "Remaining steps do not fit in `T`")]
+    fn inclusive_remaining_overflow() {
+        wrap!(=255u8).remaining();
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` is inclusive, so there is no `Range` for it.")]
+    fn inclusive_range_panics() {
+        wrap!(=255u8).range();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Mismatched bounds: left is `0..=255` but right is `0..255`.")]
+    fn mismatched_inclusive() {
+        let _ = wrap!(=255u8) + wrap!(3u8, 0, 255);
     }
 }